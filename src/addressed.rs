@@ -0,0 +1,64 @@
+//! Frames for RS-485 multi-drop buses.
+
+use core::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    check_ascii, check_line_ending, check_start, parse_body, parse_hex, to_upper_hex_pair, Command,
+    ParseError,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// [`Command`] for a single device on an RS-485 bus.
+///
+/// The device address is encoded as two hex digits
+/// right behind the start character, e.g. `{03M0905E8\r\n`
+/// sets the process temperature of device `0x03`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AddressedCommand {
+    /// Address of the device on the bus
+    pub device: u8,
+    pub command: Command,
+}
+
+impl AddressedCommand {
+    #[must_use]
+    pub const fn new(device: u8, command: Command) -> Self {
+        Self { device, command }
+    }
+
+    #[must_use]
+    pub const fn into_bytes(self) -> [u8; 12] {
+        let [_, b1, b2, b3, b4, b5, b6, b7, b8, b9] = self.command.into_bytes();
+        let [d0, d1] = to_upper_hex_pair(self.device);
+        [b'{', d0, d1, b1, b2, b3, b4, b5, b6, b7, b8, b9]
+    }
+}
+
+impl FromStr for AddressedCommand {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != 12 {
+            return Err(ParseError::MessageLength { len: s.len() });
+        }
+        check_ascii(s)?;
+        let (start, tail) = s.split_at(1);
+        let (device, tail) = tail.split_at(2);
+        let (body, line_ending) = tail.split_at(7);
+        check_start(start)?;
+        check_line_ending(line_ending, 10)?;
+        let device = parse_hex(device, 1, false)
+            .map_err(|(offset, byte)| ParseError::Device { offset, byte })?;
+        // Two hex digits always fit into a byte.
+        let device = device.to_le_bytes()[0];
+        let command = parse_body(body, 3, false)?;
+        Ok(Self { device, command })
+    }
+}
@@ -0,0 +1,65 @@
+use super::*;
+use crate::{Address, Sender};
+
+#[test]
+fn encode_addressed_command() {
+    let cmd = AddressedCommand::new(0x03, Command::set(Address::SetProcessTemp, 0x05E8));
+    assert_eq!(cmd.into_bytes(), *b"{03M0905E8\r\n");
+    let cmd = AddressedCommand::new(0xAF, Command::query(Address::InternalTemp));
+    assert_eq!(cmd.into_bytes(), *b"{AFM01****\r\n");
+}
+
+#[test]
+fn encode_all_device_addresses() {
+    for device in 0..=u8::MAX {
+        let cmd = AddressedCommand::new(device, Command::query(Address::InternalTemp));
+        let bytes = cmd.into_bytes();
+        let s = core::str::from_utf8(&bytes).unwrap();
+        assert_eq!(s.parse(), Ok(cmd));
+    }
+}
+
+#[test]
+fn parse_addressed_command() {
+    let cmd: AddressedCommand = "{1FS0109C4\r\n".parse().unwrap();
+    assert_eq!(cmd.device, 0x1F);
+    assert_eq!(cmd.command.sender, Sender::Slave);
+    assert_eq!(cmd.command.address, 0x01);
+    assert_eq!(cmd.command.data, Some(0x09C4));
+}
+
+#[test]
+fn parse_invalid_addressed_command() {
+    assert_eq!(
+        "{M0109C4\r\n".parse::<AddressedCommand>(),
+        Err(ParseError::MessageLength { len: 10 })
+    );
+    assert_eq!(
+        "{G1S0109C4\r\n".parse::<AddressedCommand>(),
+        Err(ParseError::Device {
+            offset: 1,
+            byte: b'G'
+        })
+    );
+    assert_eq!(
+        "[01S0109C4\r\n".parse::<AddressedCommand>(),
+        Err(ParseError::StartByte { byte: b'[' })
+    );
+    assert_eq!(
+        "{01X0109C4\r\n".parse::<AddressedCommand>(),
+        Err(ParseError::Sender {
+            offset: 3,
+            byte: b'X'
+        })
+    );
+}
+
+#[test]
+fn addressed_round_trip() {
+    for device in [0x00, 0x01, 0x7F, 0xFF] {
+        let cmd = AddressedCommand::new(device, Command::set(Address::Circulation, 1));
+        let bytes = cmd.into_bytes();
+        let s = core::str::from_utf8(&bytes).unwrap();
+        assert_eq!(s.parse(), Ok(cmd));
+    }
+}
@@ -0,0 +1,61 @@
+use core::ops::RangeInclusive;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Address, Temperature, DELTA_LIMIT_RANGE, SETPOINT_RANGE};
+
+/// Configurable alarm limit of the device.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AlarmLimit {
+    /// Over-temperature limit
+    Upper,
+    /// Under-temperature limit
+    Lower,
+    /// Maximum deviation above the setpoint
+    UpperDelta,
+    /// Maximum deviation below the setpoint
+    LowerDelta,
+}
+
+impl AlarmLimit {
+    #[must_use]
+    pub const fn address(self) -> Address {
+        match self {
+            Self::Upper => Address::UpperAlarmLimit,
+            Self::Lower => Address::LowerAlarmLimit,
+            Self::UpperDelta => Address::UpperDeltaLimit,
+            Self::LowerDelta => Address::LowerDeltaLimit,
+        }
+    }
+
+    /// Temperatures the device accepts for the limit.
+    #[must_use]
+    pub const fn range(self) -> RangeInclusive<Temperature> {
+        match self {
+            Self::Upper | Self::Lower => SETPOINT_RANGE,
+            Self::UpperDelta | Self::LowerDelta => DELTA_LIMIT_RANGE,
+        }
+    }
+}
+
+impl From<AlarmLimit> for Address {
+    fn from(from: AlarmLimit) -> Self {
+        from.address()
+    }
+}
+
+impl TryFrom<Address> for AlarmLimit {
+    type Error = Address;
+    fn try_from(from: Address) -> Result<Self, Address> {
+        match from {
+            Address::UpperAlarmLimit => Ok(Self::Upper),
+            Address::LowerAlarmLimit => Ok(Self::Lower),
+            Address::UpperDeltaLimit => Ok(Self::UpperDelta),
+            Address::LowerDeltaLimit => Ok(Self::LowerDelta),
+            x => Err(x),
+        }
+    }
+}
@@ -0,0 +1,219 @@
+//! Asynchronous client for `tokio` transports.
+
+use std::{
+    io::Result,
+    time::{Duration, SystemTime},
+};
+
+use futures_core::Stream;
+use futures_util::stream;
+
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+    time::{sleep, sleep_until, timeout, Instant},
+};
+
+#[cfg(feature = "tracing")]
+use crate::client::{exchange_span, trace_result};
+use crate::{
+    client::{confirm_lock, reply_data},
+    frame::FrameBuf,
+    helpers::client_helpers,
+    poller::Reading,
+    rate_limit::RateLimiter,
+    retry::is_retryable,
+    Address, Command, Exchange, FrameFormat, RetryPolicy, TimeoutError, Timeouts,
+    DEFAULT_MIN_INTERVAL,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Asynchronous Pilot ONE client.
+///
+/// This is the async counterpart of [`PilotOne`](crate::PilotOne).
+/// Each exchange fails with [`TimeoutError`] if the device
+/// does not reply in time.
+#[derive(Debug)]
+pub struct AsyncPilotOne<T> {
+    transport: T,
+    timeouts: Timeouts,
+    retry_policy: RetryPolicy,
+    rate_limiter: RateLimiter,
+    format: FrameFormat,
+}
+
+impl<T> AsyncPilotOne<T> {
+    #[must_use]
+    pub const fn new(transport: T) -> Self {
+        Self {
+            transport,
+            timeouts: Timeouts::DEFAULT,
+            retry_policy: RetryPolicy::NONE,
+            rate_limiter: RateLimiter::new(DEFAULT_MIN_INTERVAL),
+            format: FrameFormat::Plain,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_frame_format(mut self, format: FrameFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    #[must_use]
+    pub const fn frame_format(&self) -> FrameFormat {
+        self.format
+    }
+
+    #[must_use]
+    pub const fn with_timeouts(mut self, timeouts: Timeouts) -> Self {
+        self.timeouts = timeouts;
+        self
+    }
+
+    #[must_use]
+    pub const fn timeouts(&self) -> Timeouts {
+        self.timeouts
+    }
+
+    #[must_use]
+    pub const fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    #[must_use]
+    pub const fn retry_policy(&self) -> RetryPolicy {
+        self.retry_policy
+    }
+
+    /// Set the minimum time between the end of an exchange and the next command.
+    #[must_use]
+    pub const fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.rate_limiter.min_interval = min_interval;
+        self
+    }
+
+    #[must_use]
+    pub const fn min_interval(&self) -> Duration {
+        self.rate_limiter.min_interval
+    }
+
+    #[must_use]
+    pub const fn get_ref(&self) -> &T {
+        &self.transport
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.transport
+    }
+
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.transport
+    }
+}
+
+impl<T> AsyncPilotOne<T>
+where
+    T: AsyncRead + AsyncWrite + Unpin,
+{
+    /// Send a command and wait for the reply.
+    ///
+    /// Failed exchanges are repeated according to the [`RetryPolicy`].
+    pub async fn exchange(&mut self, cmd: Command) -> Result<Command> {
+        let exchange = self.exchange_with_retries(cmd);
+        #[cfg(feature = "tracing")]
+        let exchange = tracing::Instrument::instrument(exchange, exchange_span(cmd));
+        exchange.await
+    }
+
+    async fn exchange_with_retries(&mut self, cmd: Command) -> Result<Command> {
+        let mut retry = 0;
+        loop {
+            match self.exchange_once(cmd).await {
+                Err(err) if retry < self.retry_policy.max_retries && is_retryable(&err) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(retry, error = %err, "Retrying exchange");
+                    sleep(self.retry_policy.delay(retry)).await;
+                    retry += 1;
+                }
+                res => return res,
+            }
+        }
+    }
+
+    client_helpers!(std::io::Error, async.await);
+
+    /// Query `addresses` every `interval` and yield the decoded values.
+    ///
+    /// The next cycle starts `interval` after the previous one has
+    /// started or immediately if the queries took longer. Failed
+    /// queries are yielded as errors without ending the stream.
+    /// Without addresses the stream ends immediately.
+    pub fn readings<I>(
+        &mut self,
+        addresses: I,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<Reading>> + '_
+    where
+        I: IntoIterator<Item = Address>,
+    {
+        let addresses: Vec<_> = addresses.into_iter().collect();
+        let state = (self, addresses, Instant::now(), 0);
+        stream::unfold(
+            state,
+            move |(client, addresses, mut due, index)| async move {
+                let address = *addresses.get(index)?;
+                if index == 0 {
+                    sleep_until(due).await;
+                    due = Instant::now() + interval;
+                }
+                let reading = client.read(address).await.map(|response| Reading {
+                    timestamp: SystemTime::now(),
+                    response,
+                });
+                let next = (index + 1) % addresses.len();
+                Some((reading, (client, addresses, due, next)))
+            },
+        )
+    }
+
+    async fn exchange_once(&mut self, cmd: Command) -> Result<Command> {
+        sleep(self.rate_limiter.remaining()).await;
+        #[cfg(feature = "tracing")]
+        let started = std::time::Instant::now();
+        let exchange_timeout = self.timeouts.exchange;
+        let res = timeout(exchange_timeout, self.exchange_without_timeout(cmd))
+            .await
+            .unwrap_or_else(|_| {
+                Err(TimeoutError {
+                    timeout: exchange_timeout,
+                }
+                .into())
+            });
+        self.rate_limiter.finish();
+        #[cfg(feature = "tracing")]
+        trace_result(&res, started.elapsed());
+        res
+    }
+
+    async fn exchange_without_timeout(&mut self, cmd: Command) -> Result<Command> {
+        let exchange = Exchange::new(cmd);
+        let request = self.format.encode(cmd);
+        #[cfg(feature = "tracing")]
+        tracing::trace!(tx = %request.as_bytes().escape_ascii(), "Sending frame");
+        self.transport.write_all(&request).await?;
+        self.transport.flush().await?;
+        let mut frame = FrameBuf::new(self.format);
+        loop {
+            let byte = self.transport.read_u8().await?;
+            if let Some(res) = frame.push(byte) {
+                #[cfg(feature = "tracing")]
+                tracing::trace!(rx = %frame.last_frame().escape_ascii(), "Received frame");
+                return Ok(exchange.verify(res?)?);
+            }
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use std::{io::ErrorKind, pin::pin};
+
+use futures_util::StreamExt as _;
+
+use tokio::io::duplex;
+
+use super::*;
+use crate::{Response, Temperature};
+
+#[tokio::test]
+async fn get_internal_temp() {
+    let (transport, mut device) = duplex(64);
+    let mut client = AsyncPilotOne::new(transport);
+    device.write_all(b"{S0109C4\r\n").await.unwrap();
+    let temp = client.get_internal_temp().await.unwrap();
+    assert_eq!(temp, Temperature::from_centi_celsius(2500));
+    let mut request = [0; 10];
+    device.read_exact(&mut request).await.unwrap();
+    assert_eq!(&request, b"{M01****\r\n");
+}
+
+#[tokio::test]
+async fn set_setpoint() {
+    let (transport, mut device) = duplex(64);
+    let mut client = AsyncPilotOne::new(transport);
+    device.write_all(b"{S00FF38\r\n").await.unwrap();
+    let temp = Temperature::from_centi_celsius(-200);
+    assert_eq!(client.set_setpoint(temp).await.unwrap(), temp);
+}
+
+#[tokio::test]
+async fn exchange_timeout() {
+    let (transport, _device) = duplex(64);
+    let mut client = AsyncPilotOne::new(transport).with_timeouts(Timeouts {
+        exchange: Duration::from_millis(10),
+    });
+    let err = client.get_internal_temp().await.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::TimedOut);
+}
+
+#[tokio::test]
+async fn reply_without_data() {
+    let (transport, mut device) = duplex(64);
+    let mut client = AsyncPilotOne::new(transport);
+    device.write_all(b"{S01****\r\n").await.unwrap();
+    let err = client.query(Address::InternalTemp).await.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
+
+#[tokio::test]
+async fn timeout_error() {
+    let (transport, _device) = duplex(64);
+    let mut client = AsyncPilotOne::new(transport)
+        .with_timeouts(Timeouts {
+            exchange: Duration::from_millis(10),
+        })
+        .with_retry_policy(RetryPolicy::new(1));
+    let err = client.get_internal_temp().await.unwrap_err();
+    let err = err
+        .into_inner()
+        .unwrap()
+        .downcast::<TimeoutError>()
+        .unwrap();
+    assert_eq!(err.timeout, Duration::from_millis(10));
+}
+
+#[tokio::test]
+async fn retry_after_corrupted_reply() {
+    let (transport, mut device) = duplex(64);
+    let mut client = AsyncPilotOne::new(transport).with_retry_policy(RetryPolicy::new(1));
+    device.write_all(b"{X0109C4\r\n{S0109C4\r\n").await.unwrap();
+    let temp = client.get_internal_temp().await.unwrap();
+    assert_eq!(temp, Temperature::from_centi_celsius(2500));
+    let mut requests = [0; 20];
+    device.read_exact(&mut requests).await.unwrap();
+    assert_eq!(&requests, b"{M01****\r\n{M01****\r\n");
+}
+
+#[tokio::test]
+async fn pace_commands() {
+    let (transport, mut device) = duplex(64);
+    let interval = Duration::from_millis(30);
+    let mut client = AsyncPilotOne::new(transport).with_min_interval(interval);
+    device.write_all(b"{S0109C4\r\n{S0109C4\r\n").await.unwrap();
+    client.get_internal_temp().await.unwrap();
+    let start = std::time::Instant::now();
+    client.get_internal_temp().await.unwrap();
+    assert!(start.elapsed() >= interval);
+}
+
+#[tokio::test]
+async fn readings() {
+    let (transport, mut device) = duplex(64);
+    let mut client = AsyncPilotOne::new(transport);
+    device
+        .write_all(b"{S0109C4\r\n{S070000\r\n{S01****\r\n")
+        .await
+        .unwrap();
+    let readings: Vec<_> = client
+        .readings(
+            [Address::InternalTemp, Address::ProcessTemp],
+            Duration::ZERO,
+        )
+        .take(3)
+        .collect()
+        .await;
+    assert_eq!(
+        readings[0].as_ref().unwrap().response,
+        Response::InternalTemp(Temperature::from_centi_celsius(2500))
+    );
+    assert_eq!(
+        readings[1].as_ref().unwrap().response,
+        Response::ProcessTemp(Temperature::from_centi_celsius(0))
+    );
+    assert!(readings[2].is_err());
+}
+
+#[tokio::test]
+async fn readings_without_addresses() {
+    let (transport, _device) = duplex(64);
+    let mut client = AsyncPilotOne::new(transport);
+    let mut readings = pin!(client.readings([], Duration::ZERO));
+    assert!(readings.next().await.is_none());
+}
@@ -0,0 +1,72 @@
+//! Encode multiple commands into a single buffer.
+
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io::{Error, ErrorKind};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Command, FrameFormat};
+
+#[cfg(test)]
+mod tests;
+
+/// The buffer is too small to hold all frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BufferOverflow {
+    /// Number of bytes of the complete frames
+    /// that have been written before
+    pub written: usize,
+}
+
+impl fmt::Display for BufferOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Buffer overflow after {} bytes", self.written)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BufferOverflow {}
+
+#[cfg(feature = "std")]
+impl From<BufferOverflow> for Error {
+    fn from(e: BufferOverflow) -> Error {
+        Error::new(ErrorKind::InvalidInput, e)
+    }
+}
+
+/// Encode `commands` as consecutive plain frames into `buf`.
+///
+/// Returns the number of bytes written. Frames are never
+/// written partially.
+pub fn encode_batch<I>(commands: I, buf: &mut [u8]) -> Result<usize, BufferOverflow>
+where
+    I: IntoIterator<Item = Command>,
+{
+    FrameFormat::Plain.encode_batch(commands, buf)
+}
+
+impl FrameFormat {
+    /// Encode `commands` as consecutive frames of this format into `buf`.
+    ///
+    /// Returns the number of bytes written. Frames are never
+    /// written partially.
+    pub fn encode_batch<I>(self, commands: I, buf: &mut [u8]) -> Result<usize, BufferOverflow>
+    where
+        I: IntoIterator<Item = Command>,
+    {
+        let mut written = 0;
+        for cmd in commands {
+            let frame = self.encode(cmd);
+            let Some(dst) = buf.get_mut(written..written + frame.len()) else {
+                return Err(BufferOverflow { written });
+            };
+            dst.copy_from_slice(&frame);
+            written += frame.len();
+        }
+        Ok(written)
+    }
+}
@@ -0,0 +1,39 @@
+use super::*;
+use crate::Address;
+
+#[test]
+fn encode_multiple_frames() {
+    let mut buf = [0; 32];
+    let commands = [
+        Command::query(Address::InternalTemp),
+        Command::set(Address::SetProcessTemp, 0x05E8),
+    ];
+    assert_eq!(encode_batch(commands, &mut buf), Ok(20));
+    assert_eq!(&buf[..20], b"{M01****\r\n{M0905E8\r\n");
+    assert_eq!(encode_batch([], &mut buf), Ok(0));
+}
+
+#[test]
+fn encode_multiple_frames_with_checksum() {
+    let mut buf = [0; 24];
+    let commands = [
+        Command::query(Address::InternalTemp),
+        Command::set(Address::SetProcessTemp, 0x05E8),
+    ];
+    assert_eq!(
+        FrameFormat::Checksum.encode_batch(commands, &mut buf),
+        Ok(24)
+    );
+    assert_eq!(&buf, b"{M01****D1\r\n{M0905E813\r\n");
+}
+
+#[test]
+fn buffer_overflow() {
+    let mut buf = [0; 19];
+    let cmd = Command::query(Address::InternalTemp);
+    assert_eq!(
+        encode_batch([cmd, cmd], &mut buf),
+        Err(BufferOverflow { written: 10 })
+    );
+    assert_eq!(&buf[10..], [0; 9]);
+}
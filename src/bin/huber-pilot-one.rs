@@ -0,0 +1,254 @@
+//! Command line tool for commissioning and troubleshooting.
+
+use std::{
+    env,
+    io::{Error, ErrorKind, Read, Result, Write},
+    net::{IpAddr, SocketAddr},
+    ops::ControlFlow,
+    process::ExitCode,
+    time::Duration,
+};
+
+use huber_pilot_one::{
+    modbus, poller::Poller, serial, tcp, Address, Command, OnOff, OperationLock, PilotOne, Sender,
+    TempControlMode,
+};
+
+const USAGE: &str = "\
+Usage: huber-pilot-one <DEVICE> <COMMAND>
+
+DEVICE:
+    Serial port (e.g. /dev/ttyUSB0) or IP address
+    of a Com.G@te (e.g. 192.168.1.10 or 192.168.1.10:8101)
+
+COMMAND:
+    get <PARAMETER>          Read a parameter
+    set <PARAMETER> <VALUE>  Write a parameter
+    monitor [SECONDS]        Read the temperatures and messages periodically
+    raw <FRAME>              Send a raw frame, e.g. {M01****
+    parameters               List all parameters
+
+Temperatures are given in °C, switches as on/off.";
+
+const PARAMETERS: [(&str, Address); 30] = [
+    ("setpoint", Address::SetpointTempControl),
+    ("internal-temp", Address::InternalTemp),
+    ("pump-pressure", Address::PumpPressure),
+    ("power", Address::CurrentPower),
+    ("error", Address::ErrorReport),
+    ("warning", Address::WarningMessage),
+    ("process-temp", Address::ProcessTemp),
+    ("set-process-temp", Address::SetProcessTemp),
+    ("status", Address::Status),
+    ("temp-control-mode", Address::TempControlMode),
+    ("temp-control", Address::TempControl),
+    ("circulation", Address::Circulation),
+    ("operation-lock", Address::OperationLock),
+    ("degassing", Address::Degassing),
+    (
+        "process-temp-actual-setting-mode",
+        Address::ProcessTempActualSettingMode,
+    ),
+    ("upper-alarm-limit", Address::UpperAlarmLimit),
+    ("lower-alarm-limit", Address::LowerAlarmLimit),
+    ("upper-delta-limit", Address::UpperDeltaLimit),
+    ("lower-delta-limit", Address::LowerDeltaLimit),
+    ("pump-speed", Address::PumpSpeed),
+    ("pump-speed-setpoint", Address::PumpSpeedSetpoint),
+    ("digital-inputs", Address::ComGateDigitalInputs),
+    ("digital-outputs", Address::ComGateDigitalOutputs),
+    ("analog-input", Address::ComGateAnalogInput),
+    ("analog-output", Address::ComGateAnalogOutput),
+    ("ecs-standby", Address::EcsStandby),
+    ("device-type", Address::DeviceType),
+    ("firmware-version", Address::FirmwareVersion),
+    ("serial-number-high", Address::SerialNumberHigh),
+    ("serial-number-low", Address::SerialNumberLow),
+];
+
+const MONITORED: [Address; 5] = [
+    Address::SetpointTempControl,
+    Address::InternalTemp,
+    Address::ProcessTemp,
+    Address::ErrorReport,
+    Address::WarningMessage,
+];
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    match args.as_slice() {
+        [] | ["-h" | "--help"] => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        ["parameters"] | [_, "parameters"] => {
+            for (name, _) in PARAMETERS {
+                println!("{name}");
+            }
+            return ExitCode::SUCCESS;
+        }
+        _ => {}
+    }
+    let res = match args.as_slice() {
+        [device, command @ ..] if !command.is_empty() => open(device, command),
+        _ => Err(usage_error()),
+    };
+    match res {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Error: {err}");
+            if err.kind() == ErrorKind::InvalidInput {
+                eprintln!("\n{USAGE}");
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn open(device: &str, command: &[&str]) -> Result<()> {
+    if let Ok(ip) = device.parse::<IpAddr>() {
+        return run(&mut tcp::connect(ip)?, command);
+    }
+    if let Ok(addr) = device.parse::<SocketAddr>() {
+        return run(&mut tcp::Connector::with_addr(addr).connect()?, command);
+    }
+    run(&mut serial::open(device)?, command)
+}
+
+fn run<T: Read + Write>(client: &mut PilotOne<T>, command: &[&str]) -> Result<()> {
+    match *command {
+        ["get", parameter] => {
+            let address = parse_parameter(parameter)?;
+            let data = client.query(address)?;
+            print_reply(address, data);
+        }
+        ["set", parameter, value] => {
+            let address = parse_parameter(parameter)?;
+            let data = parse_value(address, value)?;
+            // Don't send values the device would reject.
+            Command::set(address, data).validate()?;
+            let data = client.set(address, data)?;
+            print_reply(address, data);
+        }
+        ["monitor"] => monitor(client, Duration::from_secs(1)),
+        ["monitor", seconds] => {
+            let interval = seconds
+                .parse()
+                .ok()
+                .and_then(|x| Duration::try_from_secs_f64(x).ok())
+                .filter(|interval| !interval.is_zero())
+                .ok_or_else(|| invalid_input(format!("Invalid interval: {seconds}")))?;
+            monitor(client, interval);
+        }
+        ["raw", frame] => {
+            let cmd = Command::from_str_lenient(frame)
+                .map_err(|err| invalid_input(format!("Invalid frame: {err}")))?;
+            let reply = client.exchange(cmd)?;
+            println!("{reply} ({reply:#})");
+        }
+        _ => return Err(usage_error()),
+    }
+    Ok(())
+}
+
+fn monitor<T: Read + Write>(client: &mut PilotOne<T>, interval: Duration) {
+    let mut poller = Poller::new();
+    for address in MONITORED {
+        poller.add(address, interval);
+    }
+    poller.run(client, |reading| {
+        match reading {
+            Ok(reading) => {
+                let response = reading.response;
+                print_reply(response.address(), response.data());
+            }
+            Err(err) => eprintln!("Error: {err}"),
+        }
+        ControlFlow::Continue(())
+    });
+}
+
+fn print_reply(address: Address, data: u16) {
+    let reply = Command {
+        sender: Sender::Slave,
+        address: address.into(),
+        data: Some(data),
+    };
+    println!("{reply:#}");
+}
+
+fn parse_parameter(name: &str) -> Result<Address> {
+    PARAMETERS
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, address)| *address)
+        .ok_or_else(|| invalid_input(format!("Unknown parameter: {name}")))
+}
+
+fn parse_value(address: Address, value: &str) -> Result<u16> {
+    let data = match (address, value) {
+        (Address::TempControlMode, "internal") => Some(TempControlMode::Internal.into()),
+        (Address::TempControlMode, "process") => Some(TempControlMode::Process.into()),
+        (Address::OperationLock, "locked") => Some(OperationLock::Locked.into()),
+        (Address::OperationLock, "unlocked") => Some(OperationLock::Unlocked.into()),
+        (Address::TempControl | Address::Circulation | Address::Degassing, "on") => {
+            Some(OnOff::On.into())
+        }
+        (Address::TempControl | Address::Circulation | Address::Degassing, "off") => {
+            Some(OnOff::Off.into())
+        }
+        (_, "on" | "off") => None,
+        _ => value
+            .parse()
+            .ok()
+            .and_then(|x| modbus::register(address).scaling.from_f64(x)),
+    };
+    data.ok_or_else(|| invalid_input(format!("Invalid value: {value}")))
+}
+
+fn invalid_input(msg: String) -> Error {
+    Error::new(ErrorKind::InvalidInput, msg)
+}
+
+fn usage_error() -> Error {
+    invalid_input("Invalid arguments".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    fn client() -> PilotOne<Cursor<Vec<u8>>> {
+        PilotOne::new(Cursor::new(Vec::new()))
+    }
+
+    #[test]
+    fn parse_switch_values() {
+        assert_eq!(parse_value(Address::Circulation, "on").unwrap(), 1);
+        assert_eq!(parse_value(Address::Degassing, "off").unwrap(), 0);
+        let err = parse_value(Address::SetpointTempControl, "on").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        assert!(parse_value(Address::OperationLock, "off").is_err());
+    }
+
+    #[test]
+    fn set_setpoint_on() {
+        let mut client = client();
+        let err = run(&mut client, &["set", "setpoint", "on"]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        assert!(client.get_ref().get_ref().is_empty());
+    }
+
+    #[test]
+    fn monitor_zero() {
+        let mut client = client();
+        for seconds in ["0", "0.0", "-1"] {
+            let err = run(&mut client, &["monitor", seconds]).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        }
+        assert!(client.get_ref().get_ref().is_empty());
+    }
+}
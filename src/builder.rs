@@ -0,0 +1,163 @@
+//! Construct [`Command`]s step by step.
+
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io::{Error, ErrorKind};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Address, Command, Response, Sender, ValidationError};
+
+#[cfg(test)]
+mod tests;
+
+/// The builder does not describe a valid command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum BuildError {
+    /// No address has been set
+    MissingAddress,
+    /// Slave replies must carry data
+    MissingData,
+    /// The typed value belongs to a different address
+    AddressMismatch { address: Address, value: Address },
+    /// The device would reject the command
+    Invalid(ValidationError),
+}
+
+impl From<ValidationError> for BuildError {
+    fn from(from: ValidationError) -> Self {
+        Self::Invalid(from)
+    }
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::MissingAddress => write!(f, "Missing command address"),
+            Self::MissingData => write!(f, "Missing command data"),
+            Self::AddressMismatch { address, value } => {
+                write!(f, "Value of {value:?} does not match address {address:?}")
+            }
+            Self::Invalid(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BuildError {}
+
+#[cfg(feature = "std")]
+impl From<BuildError> for Error {
+    fn from(e: BuildError) -> Error {
+        Error::new(ErrorKind::InvalidInput, e)
+    }
+}
+
+/// Builder that checks the command for consistency.
+///
+/// In addition to [`Command::validate`] it ensures that
+/// an address is set, that slave replies carry data and
+/// that typed values match the address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommandBuilder {
+    sender: Sender,
+    address: Option<Address>,
+    data: Option<u16>,
+    value_address: Option<Address>,
+}
+
+impl Default for CommandBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CommandBuilder {
+    /// Start a master query without address.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            sender: Sender::Master,
+            address: None,
+            data: None,
+            value_address: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn sender(mut self, sender: Sender) -> Self {
+        self.sender = sender;
+        self
+    }
+
+    #[must_use]
+    pub const fn address(mut self, address: Address) -> Self {
+        self.address = Some(address);
+        self
+    }
+
+    /// Set the raw data word.
+    #[must_use]
+    pub const fn data(mut self, data: u16) -> Self {
+        self.data = Some(data);
+        self.value_address = None;
+        self
+    }
+
+    /// Set a typed value.
+    ///
+    /// The address is taken from the value unless
+    /// it has been set explicitly.
+    #[must_use]
+    pub fn value(mut self, value: Response) -> Self {
+        self.data = Some(value.data());
+        self.value_address = Some(value.address());
+        self
+    }
+
+    /// Remove the data to build a query.
+    ///
+    /// The address of a typed value is kept.
+    #[must_use]
+    pub const fn query(mut self) -> Self {
+        if self.address.is_none() {
+            self.address = self.value_address;
+        }
+        self.data = None;
+        self.value_address = None;
+        self
+    }
+
+    pub fn build(self) -> Result<Command, BuildError> {
+        let address = self
+            .address
+            .or(self.value_address)
+            .ok_or(BuildError::MissingAddress)?;
+        if let Some(value) = self.value_address {
+            if value != address {
+                return Err(BuildError::AddressMismatch { address, value });
+            }
+        }
+        if self.sender == Sender::Slave && self.data.is_none() {
+            return Err(BuildError::MissingData);
+        }
+        let cmd = Command {
+            sender: self.sender,
+            address: address as u8,
+            data: self.data,
+        };
+        cmd.validate()?;
+        Ok(cmd)
+    }
+}
+
+impl Command {
+    /// Shortcut for [`CommandBuilder::new`].
+    #[must_use]
+    pub const fn builder() -> CommandBuilder {
+        CommandBuilder::new()
+    }
+}
@@ -0,0 +1,75 @@
+use super::*;
+use crate::{OnOff, Temperature};
+
+#[test]
+fn build_query() {
+    let cmd = Command::builder()
+        .address(Address::InternalTemp)
+        .build()
+        .unwrap();
+    assert_eq!(cmd, Command::query(Address::InternalTemp));
+    assert_eq!(
+        CommandBuilder::new().build(),
+        Err(BuildError::MissingAddress)
+    );
+}
+
+#[test]
+fn build_typed_value() {
+    let temp = Temperature::from_centi_celsius(1512);
+    let cmd = CommandBuilder::new()
+        .value(Response::SetProcessTemp(temp))
+        .build()
+        .unwrap();
+    assert_eq!(cmd, Command::set(Address::SetProcessTemp, 0x05E8));
+
+    assert_eq!(
+        CommandBuilder::new()
+            .address(Address::Circulation)
+            .value(Response::TempControl(OnOff::On))
+            .build(),
+        Err(BuildError::AddressMismatch {
+            address: Address::Circulation,
+            value: Address::TempControl
+        })
+    );
+    let cmd = CommandBuilder::new()
+        .value(Response::TempControl(OnOff::On))
+        .query()
+        .build();
+    assert_eq!(cmd, Ok(Command::query(Address::TempControl)));
+}
+
+#[test]
+fn build_invalid_command() {
+    assert_eq!(
+        CommandBuilder::new()
+            .address(Address::InternalTemp)
+            .data(0x05E8)
+            .build(),
+        Err(BuildError::Invalid(ValidationError::ReadOnly(
+            Address::InternalTemp
+        )))
+    );
+    assert_eq!(
+        CommandBuilder::new()
+            .address(Address::Circulation)
+            .data(2)
+            .build(),
+        Err(BuildError::Invalid(ValidationError::OutOfRange {
+            address: Address::Circulation,
+            data: 2
+        }))
+    );
+}
+
+#[test]
+fn build_slave_reply() {
+    let builder = CommandBuilder::new()
+        .sender(Sender::Slave)
+        .address(Address::InternalTemp);
+    assert_eq!(builder.build(), Err(BuildError::MissingData));
+    let cmd = builder.data(0x09C4).build().unwrap();
+    assert_eq!(cmd.sender, Sender::Slave);
+    assert_eq!(cmd.data, Some(0x09C4));
+}
@@ -0,0 +1,29 @@
+//! Signed values with a resolution of 0.01.
+
+use core::fmt;
+
+/// Value in hundredths of `x`.
+///
+/// Returns `None` if the value can't be represented.
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) fn from_f64(x: f64) -> Option<i16> {
+    let x = x * 100.0;
+    if !(f64::from(i16::MIN)..=f64::from(i16::MAX)).contains(&x) {
+        return None;
+    }
+    // `f64::round` is not available in `core`
+    let x = if x < 0.0 { x - 0.5 } else { x + 0.5 };
+    Some(x as i16)
+}
+
+pub(crate) fn to_f64(x: i16) -> f64 {
+    f64::from(x) / 100.0
+}
+
+/// Formats `x` with two decimals followed by `unit`.
+pub(crate) fn fmt(x: i16, unit: &str, f: &mut fmt::Formatter) -> fmt::Result {
+    let x = i32::from(x);
+    let sign = if x < 0 { "-" } else { "" };
+    let x = x.abs();
+    write!(f, "{sign}{}.{:02} {unit}", x / 100, x % 100)
+}
@@ -0,0 +1,120 @@
+use core::{ops::Deref, str};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    check_ascii, check_line_ending, check_start, frame::MAX_FRAME_LEN, parse_body,
+    to_upper_hex_pair, Command, ParseError,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Checksum of a frame.
+///
+/// The checksum is the sum of all bytes from the start
+/// character up to the last data byte (modulo 256).
+#[must_use]
+pub const fn checksum(frame: &[u8]) -> u8 {
+    let mut sum = 0u8;
+    let mut i = 0;
+    while i < frame.len() {
+        sum = sum.wrapping_add(frame[i]);
+        i += 1;
+    }
+    sum
+}
+
+/// Frame format used on a connection.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FrameFormat {
+    /// Frames without checksum, e.g. `{M0905E8\r\n`
+    #[default]
+    Plain,
+    /// Frames with two hex digits of [`checksum`] in front
+    /// of the line ending, e.g. `{M0905E813\r\n`
+    Checksum,
+}
+
+impl FrameFormat {
+    /// Length of a frame (including CRLF).
+    #[must_use]
+    pub const fn frame_len(self) -> usize {
+        match self {
+            Self::Plain => 10,
+            Self::Checksum => 12,
+        }
+    }
+
+    #[must_use]
+    pub fn encode(self, cmd: Command) -> Frame {
+        let mut bytes = [0; MAX_FRAME_LEN];
+        match self {
+            Self::Plain => bytes[..10].copy_from_slice(&cmd.into_bytes()),
+            Self::Checksum => bytes.copy_from_slice(&cmd.into_bytes_with_checksum()),
+        }
+        Frame {
+            bytes,
+            len: self.frame_len(),
+        }
+    }
+
+    pub fn decode(self, s: &str) -> Result<Command, ParseError> {
+        match self {
+            Self::Plain => s.parse(),
+            Self::Checksum => Command::from_str_with_checksum(s),
+        }
+    }
+}
+
+/// Encoded frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Frame {
+    bytes: [u8; MAX_FRAME_LEN],
+    len: usize,
+}
+
+impl Frame {
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+impl Deref for Frame {
+    type Target = [u8];
+    fn deref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl Command {
+    #[must_use]
+    pub const fn into_bytes_with_checksum(self) -> [u8; 12] {
+        let [b0, b1, b2, b3, b4, b5, b6, b7, ..] = self.into_bytes();
+        let frame = [b0, b1, b2, b3, b4, b5, b6, b7];
+        let [s0, s1] = to_upper_hex_pair(checksum(&frame));
+        [b0, b1, b2, b3, b4, b5, b6, b7, s0, s1, b'\r', b'\n']
+    }
+
+    /// Parse a frame of the [`FrameFormat::Checksum`] format.
+    pub fn from_str_with_checksum(s: &str) -> Result<Self, ParseError> {
+        if s.len() != 12 {
+            return Err(ParseError::MessageLength { len: s.len() });
+        }
+        check_ascii(s)?;
+        let (frame, tail) = s.split_at(8);
+        let (sum, line_ending) = tail.split_at(2);
+        check_start(frame)?;
+        check_line_ending(line_ending, 10)?;
+        let sum_bytes = sum.as_bytes();
+        let expected = checksum(frame.as_bytes());
+        if sum_bytes != to_upper_hex_pair(expected) {
+            return Err(ParseError::Checksum { expected });
+        }
+        parse_body(&frame[1..], 1, false)
+    }
+}
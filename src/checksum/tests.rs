@@ -0,0 +1,74 @@
+use super::*;
+use crate::Address;
+
+#[test]
+fn calculate_checksum() {
+    assert_eq!(checksum(b""), 0);
+    assert_eq!(checksum(b"{M0905E8"), 0x13);
+    assert_eq!(checksum(&[0xFF, 0x02]), 0x01);
+    assert_eq!(const { checksum(b"{M0905E8") }, 0x13);
+}
+
+#[test]
+fn encode_with_checksum() {
+    let cmd = Command::set(Address::SetProcessTemp, 0x05E8);
+    assert_eq!(cmd.into_bytes_with_checksum(), *b"{M0905E813\r\n");
+    assert_eq!(
+        Command::query(Address::InternalTemp).into_bytes_with_checksum(),
+        *b"{M01****D1\r\n"
+    );
+}
+
+#[test]
+fn encode_with_checksum_all_byte_values() {
+    for x in 0..=u8::MAX {
+        let cmd = Command {
+            sender: crate::Sender::Slave,
+            address: x,
+            data: Some(u16::from_be_bytes([!x, x])),
+        };
+        let bytes = cmd.into_bytes_with_checksum();
+        let s = core::str::from_utf8(&bytes).unwrap();
+        assert_eq!(Command::from_str_with_checksum(s), Ok(cmd));
+    }
+}
+
+#[test]
+fn parse_with_checksum() {
+    let cmd = Command::from_str_with_checksum("{M0905E813\r\n").unwrap();
+    assert_eq!(cmd, Command::set(Address::SetProcessTemp, 0x05E8));
+}
+
+#[test]
+fn parse_with_invalid_checksum() {
+    assert_eq!(
+        Command::from_str_with_checksum("{M0905E812\r\n"),
+        Err(ParseError::Checksum {
+            expected: checksum(b"{M0905E8")
+        })
+    );
+    assert_eq!(
+        Command::from_str_with_checksum("{M0905E8XY\r\n"),
+        Err(ParseError::Checksum {
+            expected: checksum(b"{M0905E8")
+        })
+    );
+    assert_eq!(
+        Command::from_str_with_checksum("{M0905E8\r\n"),
+        Err(ParseError::MessageLength { len: 10 })
+    );
+}
+
+#[test]
+fn frame_format() {
+    let cmd = Command::query(Address::InternalTemp);
+    assert_eq!(&*FrameFormat::Plain.encode(cmd), b"{M01****\r\n");
+    assert_eq!(&*FrameFormat::Checksum.encode(cmd), b"{M01****D1\r\n");
+    for format in [FrameFormat::Plain, FrameFormat::Checksum] {
+        let frame = format.encode(cmd);
+        assert_eq!(frame.len(), format.frame_len());
+        let frame = core::str::from_utf8(&frame).unwrap();
+        assert_eq!(format.decode(frame), Ok(cmd));
+    }
+    assert!(FrameFormat::Checksum.decode("{M01****\r\n").is_err());
+}
@@ -0,0 +1,211 @@
+//! Blocking client for `std::io` transports.
+
+use std::{
+    io::{Error, ErrorKind, Read, Result, Write},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    frame::FrameBuf, helpers::client_helpers, rate_limit::RateLimiter, retry::is_retryable,
+    Command, Exchange, FrameFormat, OperationLock, RetryPolicy, TimeoutError, Timeouts,
+    DEFAULT_MIN_INTERVAL,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Blocking Pilot ONE client.
+///
+/// The client sends master commands over the transport and
+/// waits for the corresponding slave reply.
+#[derive(Debug)]
+pub struct PilotOne<T> {
+    transport: T,
+    format: FrameFormat,
+    timeouts: Timeouts,
+    retry_policy: RetryPolicy,
+    rate_limiter: RateLimiter,
+}
+
+impl<T> PilotOne<T> {
+    #[must_use]
+    pub const fn new(transport: T) -> Self {
+        Self {
+            transport,
+            format: FrameFormat::Plain,
+            timeouts: Timeouts::DEFAULT,
+            retry_policy: RetryPolicy::NONE,
+            rate_limiter: RateLimiter::new(DEFAULT_MIN_INTERVAL),
+        }
+    }
+
+    #[must_use]
+    pub const fn with_frame_format(mut self, format: FrameFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    #[must_use]
+    pub const fn frame_format(&self) -> FrameFormat {
+        self.format
+    }
+
+    /// The timeouts are only checked between received bytes,
+    /// so the transport must have a read timeout (e.g.
+    /// [`TcpStream::set_read_timeout`](std::net::TcpStream::set_read_timeout)).
+    /// Otherwise a silent device blocks an exchange forever.
+    #[must_use]
+    pub const fn with_timeouts(mut self, timeouts: Timeouts) -> Self {
+        self.timeouts = timeouts;
+        self
+    }
+
+    #[must_use]
+    pub const fn timeouts(&self) -> Timeouts {
+        self.timeouts
+    }
+
+    #[must_use]
+    pub const fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    #[must_use]
+    pub const fn retry_policy(&self) -> RetryPolicy {
+        self.retry_policy
+    }
+
+    /// Set the minimum time between the end of an exchange and the next command.
+    #[must_use]
+    pub const fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.rate_limiter.min_interval = min_interval;
+        self
+    }
+
+    #[must_use]
+    pub const fn min_interval(&self) -> Duration {
+        self.rate_limiter.min_interval
+    }
+
+    #[must_use]
+    pub const fn get_ref(&self) -> &T {
+        &self.transport
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.transport
+    }
+
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.transport
+    }
+}
+
+impl<T> PilotOne<T>
+where
+    T: Read + Write,
+{
+    /// Send a command and wait for the reply.
+    ///
+    /// Fails with [`ResponseMismatch`](crate::ResponseMismatch) if the received frame
+    /// is not the reply to `cmd` and with [`TimeoutError`] if the reply is not
+    /// complete within [`Timeouts::exchange`] (see [`PilotOne::with_timeouts`]).
+    /// Failed exchanges are repeated according to the [`RetryPolicy`].
+    pub fn exchange(&mut self, cmd: Command) -> Result<Command> {
+        #[cfg(feature = "tracing")]
+        let _span = exchange_span(cmd).entered();
+        let mut retry = 0;
+        loop {
+            match self.exchange_once(cmd) {
+                Err(err) if retry < self.retry_policy.max_retries && is_retryable(&err) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(retry, error = %err, "Retrying exchange");
+                    thread::sleep(self.retry_policy.delay(retry));
+                    retry += 1;
+                }
+                res => return res,
+            }
+        }
+    }
+
+    client_helpers!(Error);
+
+    fn exchange_once(&mut self, cmd: Command) -> Result<Command> {
+        thread::sleep(self.rate_limiter.remaining());
+        #[cfg(feature = "tracing")]
+        let started = Instant::now();
+        let res = self.exchange_without_rate_limit(cmd);
+        self.rate_limiter.finish();
+        #[cfg(feature = "tracing")]
+        trace_result(&res, started.elapsed());
+        res
+    }
+
+    fn exchange_without_rate_limit(&mut self, cmd: Command) -> Result<Command> {
+        let exchange = Exchange::new(cmd);
+        let timeout = TimeoutError {
+            timeout: self.timeouts.exchange,
+        };
+        let deadline = Instant::now() + timeout.timeout;
+        let request = self.format.encode(cmd);
+        #[cfg(feature = "tracing")]
+        tracing::trace!(tx = %request.as_bytes().escape_ascii(), "Sending frame");
+        self.transport.write_all(&request)?;
+        self.transport.flush()?;
+        let mut frame = FrameBuf::new(self.format);
+        let mut byte = [0];
+        loop {
+            if let Err(err) = self.transport.read_exact(&mut byte) {
+                return Err(match err.kind() {
+                    ErrorKind::TimedOut | ErrorKind::WouldBlock => timeout.into(),
+                    _ => err,
+                });
+            }
+            if let Some(res) = frame.push(byte[0]) {
+                #[cfg(feature = "tracing")]
+                tracing::trace!(rx = %frame.last_frame().escape_ascii(), "Received frame");
+                return Ok(exchange.verify(res?)?);
+            }
+            if Instant::now() > deadline {
+                return Err(timeout.into());
+            }
+        }
+    }
+}
+
+pub(crate) fn confirm_lock(actual: OperationLock, expected: OperationLock) -> Result<()> {
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(Error::new(
+            ErrorKind::InvalidData,
+            "Operation lock not confirmed",
+        ))
+    }
+}
+
+#[cfg(feature = "tracing")]
+pub(crate) fn exchange_span(cmd: Command) -> tracing::Span {
+    tracing::debug_span!("exchange", request = %format_args!("{cmd:#}"))
+}
+
+#[cfg(feature = "tracing")]
+pub(crate) fn trace_result(res: &Result<Command>, elapsed: Duration) {
+    match res {
+        Ok(reply) => {
+            tracing::debug!(reply = %format_args!("{reply:#}"), ?elapsed, "Exchange finished");
+        }
+        Err(err) => {
+            tracing::debug!(error = %err, ?elapsed, "Exchange failed");
+        }
+    }
+}
+
+pub(crate) fn reply_data(reply: Command) -> Result<u16> {
+    reply
+        .data
+        .ok_or_else(|| Error::new(ErrorKind::InvalidData, "Missing reply data"))
+}
@@ -0,0 +1,274 @@
+use std::time::Duration;
+
+use super::*;
+use crate::{
+    mock::Mock, Address, AlarmLimit, AnalogSignal, DigitalIo, ErrorCode, FirmwareVersion, OnOff,
+    PumpSpeed, Response, ResponseMismatch, SetOutcome, TempControlMode, Temperature, WarningCode,
+};
+
+#[test]
+fn get_internal_temp() {
+    let mut client = PilotOne::new(Mock::new(b"{S0109C4\r\n"));
+    let temp = client.get_internal_temp().unwrap();
+    assert_eq!(temp, Temperature::from_centi_celsius(2500));
+    assert_eq!(client.get_ref().tx, b"{M01****\r\n");
+}
+
+#[test]
+fn set_setpoint() {
+    let mut client = PilotOne::new(Mock::new(b"{S00FF38\r\n"));
+    let temp = Temperature::from_centi_celsius(-200);
+    assert_eq!(client.set_setpoint(temp).unwrap(), temp);
+    assert_eq!(client.into_inner().tx, b"{M00FF38\r\n");
+}
+
+#[test]
+fn skip_noise_in_front_of_reply() {
+    let mut client = PilotOne::new(Mock::new(b"\0\n*{S010001\r\n"));
+    assert_eq!(client.query(Address::InternalTemp).unwrap(), 1);
+}
+
+#[test]
+fn reply_without_data() {
+    let mut client = PilotOne::new(Mock::new(b"{S01****\r\n"));
+    let err = client.query(Address::InternalTemp).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn reply_too_long() {
+    let mut client = PilotOne::new(Mock::new(b"{S0100010\r\n"));
+    let err = client.query(Address::InternalTemp).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn incomplete_reply() {
+    let mut client = PilotOne::new(Mock::new(b"{S01"));
+    let err = client.query(Address::InternalTemp).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn get_error_code() {
+    let mut client = PilotOne::new(Mock::new(b"{S050002\r\n"));
+    assert_eq!(client.get_error_code().unwrap(), ErrorCode::LowLevel);
+    assert_eq!(client.get_ref().tx, b"{M05****\r\n");
+}
+
+#[test]
+fn get_warning_code() {
+    let mut client = PilotOne::new(Mock::new(b"{S060003\r\n"));
+    assert_eq!(client.get_warning_code().unwrap(), WarningCode::LowLevel);
+    assert_eq!(client.get_ref().tx, b"{M06****\r\n");
+}
+
+#[test]
+fn temp_control_mode() {
+    let mut client = PilotOne::new(Mock::new(b"{S130001\r\n{S130000\r\n{S130005\r\n"));
+    assert_eq!(
+        client.get_temp_control_mode().unwrap(),
+        TempControlMode::Process
+    );
+    assert_eq!(
+        client
+            .set_temp_control_mode(TempControlMode::Internal)
+            .unwrap(),
+        TempControlMode::Internal
+    );
+    assert_eq!(client.get_ref().tx, b"{M13****\r\n{M130000\r\n");
+    let err = client.get_temp_control_mode().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn switch() {
+    let mut client = PilotOne::new(Mock::new(b"{S160001\r\n{S140001\r\n"));
+    assert_eq!(client.get_switch(Address::Circulation).unwrap(), OnOff::On);
+    assert_eq!(
+        client.set_switch(Address::TempControl, OnOff::On).unwrap(),
+        OnOff::On
+    );
+    assert_eq!(client.get_ref().tx, b"{M16****\r\n{M140001\r\n");
+}
+
+#[test]
+fn read_response() {
+    let mut client = PilotOne::new(Mock::new(b"{S050001\r\n"));
+    assert_eq!(
+        client.read(Address::ErrorReport).unwrap(),
+        Response::ErrorReport(ErrorCode::Overtemperature)
+    );
+}
+
+#[test]
+fn lock_and_unlock_operation() {
+    let mut client = PilotOne::new(Mock::new(b"{S170001\r\n{S170000\r\n{S170001\r\n"));
+    client.lock_operation().unwrap();
+    client.unlock_operation().unwrap();
+    assert_eq!(client.get_ref().tx, b"{M170001\r\n{M170000\r\n");
+    let err = client.unlock_operation().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn response_mismatch() {
+    let mut client = PilotOne::new(Mock::new(b"{S0009C4\r\n"));
+    let err = client.get_internal_temp().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    let err = err.into_inner().unwrap();
+    assert!(err.downcast_ref::<ResponseMismatch>().is_some());
+}
+
+#[test]
+fn checksum_frame_format() {
+    let mut client =
+        PilotOne::new(Mock::new(b"{S0109C40F\r\n")).with_frame_format(FrameFormat::Checksum);
+    let temp = client.get_internal_temp().unwrap();
+    assert_eq!(temp, Temperature::from_centi_celsius(2500));
+    assert_eq!(client.get_ref().tx, b"{M01****D1\r\n");
+
+    let mut client =
+        PilotOne::new(Mock::new(b"{S0109C40E\r\n")).with_frame_format(FrameFormat::Checksum);
+    let err = client.get_internal_temp().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn retry_after_corrupted_reply() {
+    let mut client = PilotOne::new(Mock::new(b"{X0109C4\r\n{S0109C4\r\n"))
+        .with_retry_policy(RetryPolicy::new(1));
+    let temp = client.get_internal_temp().unwrap();
+    assert_eq!(temp, Temperature::from_centi_celsius(2500));
+    assert_eq!(client.get_ref().tx, b"{M01****\r\n{M01****\r\n");
+
+    let mut client = PilotOne::new(Mock::new(b"{X0109C4\r\n{S0109C4\r\n"));
+    let err = client.get_internal_temp().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn timeout_error() {
+    struct Silent;
+
+    impl Read for Silent {
+        fn read(&mut self, _: &mut [u8]) -> Result<usize> {
+            Err(ErrorKind::WouldBlock.into())
+        }
+    }
+
+    impl Write for Silent {
+        fn write(&mut self, buf: &[u8]) -> Result<usize> {
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    let timeouts = Timeouts {
+        exchange: Duration::from_millis(50),
+    };
+    let mut client = PilotOne::new(Silent)
+        .with_timeouts(timeouts)
+        .with_retry_policy(RetryPolicy::new(2));
+    let err = client.get_internal_temp().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::TimedOut);
+    let err = err
+        .into_inner()
+        .unwrap()
+        .downcast::<TimeoutError>()
+        .unwrap();
+    assert_eq!(err.timeout, Duration::from_millis(50));
+}
+
+#[test]
+fn pace_commands() {
+    let interval = Duration::from_millis(30);
+    let mut client =
+        PilotOne::new(Mock::new(b"{S0109C4\r\n{S0109C4\r\n")).with_min_interval(interval);
+    assert_eq!(client.min_interval(), interval);
+    client.get_internal_temp().unwrap();
+    let start = Instant::now();
+    client.get_internal_temp().unwrap();
+    assert!(start.elapsed() >= interval);
+}
+
+#[test]
+fn pump_speed() {
+    let mut client = PilotOne::new(Mock::new(b"{S4805DC\r\n{S2605D0\r\n"));
+    let speed = PumpSpeed::from_rpm(1500);
+    assert_eq!(client.set_pump_speed_setpoint(speed).unwrap(), speed);
+    assert_eq!(client.get_pump_speed().unwrap().rpm(), 1488);
+    assert_eq!(client.get_ref().tx, b"{M4805DC\r\n{M26****\r\n");
+}
+
+#[test]
+fn com_gate_io() {
+    let mut client = PilotOne::new(Mock::new(b"{S5A0001\r\n{S5B0004\r\n{S5E0001\r\n"));
+    assert!(client.get_digital_inputs().unwrap().is_set(0));
+    let outputs = DigitalIo::default().with(2, true);
+    assert_eq!(client.set_digital_outputs(outputs).unwrap(), outputs);
+    assert_eq!(client.get_ecs_standby().unwrap(), OnOff::On);
+    assert_eq!(client.get_ref().tx, b"{M5A****\r\n{M5B0004\r\n{M5E****\r\n");
+}
+
+#[test]
+fn identify() {
+    let mut client = PilotOne::new(Mock::new(
+        b"{S6A0123\r\n{S6B0205\r\n{S6C0001\r\n{S6DE240\r\n",
+    ));
+    let info = client.identify().unwrap();
+    assert_eq!(info.device_type, 0x0123);
+    assert_eq!(info.firmware_version, FirmwareVersion::new(2, 5));
+    assert_eq!(info.serial_number, 123_456);
+    assert_eq!(
+        client.get_ref().tx,
+        b"{M6A****\r\n{M6B****\r\n{M6C****\r\n{M6D****\r\n"
+    );
+}
+
+#[test]
+fn set_verified() {
+    let mut client = PilotOne::new(Mock::new(b"{S0005E8\r\n{S0004B0\r\n{S00****\r\n"));
+    let set = |client: &mut PilotOne<_>| client.set_verified(Address::SetpointTempControl, 0x05E8);
+    assert_eq!(set(&mut client).unwrap(), SetOutcome::Accepted);
+    assert_eq!(
+        set(&mut client).unwrap(),
+        SetOutcome::Clamped { actual: 0x04B0 }
+    );
+    assert_eq!(set(&mut client).unwrap(), SetOutcome::Rejected);
+}
+
+#[test]
+fn typed_setters_validate() {
+    let mut client = PilotOne::new(Mock::new(b""));
+    let err = client
+        .set_setpoint(Temperature::from_centi_celsius(-20_000))
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    let err = client
+        .set_analog_output(AnalogSignal::from_data(20_001))
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    assert!(client.get_ref().tx.is_empty());
+}
+
+#[test]
+fn set_alarm_limit() {
+    let mut client = PilotOne::new(Mock::new(b"{S1B2710\r\n{S1D****\r\n"));
+    let temp = Temperature::from_centi_celsius(10_000);
+    assert_eq!(
+        client.set_alarm_limit(AlarmLimit::Upper, temp).unwrap(),
+        temp
+    );
+    assert_eq!(client.get_ref().tx, b"{M1B2710\r\n");
+    let err = client
+        .set_alarm_limit(
+            AlarmLimit::UpperDelta,
+            Temperature::from_centi_celsius(-100),
+        )
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    assert_eq!(client.get_ref().tx, b"{M1B2710\r\n");
+}
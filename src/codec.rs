@@ -0,0 +1,65 @@
+//! [`tokio_util::codec`] support for framing a byte stream into [`Command`]s.
+
+use std::{io::Error, str};
+
+use bytes::{BufMut, BytesMut};
+use tokio_util::codec::{Decoder, Encoder};
+
+use crate::{Command, FrameFormat, ParseError};
+
+#[cfg(test)]
+mod tests;
+
+/// Codec that encodes and decodes PB frames.
+///
+/// Wrap any `AsyncRead + AsyncWrite` transport (e.g. a serial port or a
+/// TCP stream) in [`tokio_util::codec::Framed`] to get a
+/// `Stream<Item = Result<Command, Error>>` and a `Sink<Command>`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PilotOneCodec {
+    format: FrameFormat,
+}
+
+impl PilotOneCodec {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::with_frame_format(FrameFormat::Plain)
+    }
+
+    #[must_use]
+    pub const fn with_frame_format(format: FrameFormat) -> Self {
+        Self { format }
+    }
+}
+
+impl Decoder for PilotOneCodec {
+    type Item = Command;
+    type Error = Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Command>, Error> {
+        let Some(pos) = src.iter().position(|b| *b == b'\n') else {
+            if src.len() > self.format.frame_len() {
+                // There is no way to recover a frame from
+                // this data so we drop it.
+                let len = src.len();
+                src.clear();
+                return Err(ParseError::MessageLength { len }.into());
+            }
+            return Ok(None);
+        };
+        let frame = src.split_to(pos + 1);
+        let frame = str::from_utf8(&frame).map_err(|e| ParseError::NonAsciiStr {
+            offset: e.valid_up_to(),
+        })?;
+        Ok(Some(self.format.decode(frame)?))
+    }
+}
+
+impl Encoder<Command> for PilotOneCodec {
+    type Error = Error;
+
+    fn encode(&mut self, cmd: Command, dst: &mut BytesMut) -> Result<(), Error> {
+        dst.put_slice(&self.format.encode(cmd));
+        Ok(())
+    }
+}
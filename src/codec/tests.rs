@@ -0,0 +1,60 @@
+use super::*;
+use crate::Sender::*;
+
+#[test]
+fn decode_complete_frame() {
+    let mut codec = PilotOneCodec::new();
+    let mut buf = BytesMut::from(&b"{S0105E8\r\n"[..]);
+    let cmd = codec.decode(&mut buf).unwrap().unwrap();
+    assert_eq!(
+        cmd,
+        Command {
+            sender: Slave,
+            address: 0x01,
+            data: Some(0x05E8),
+        }
+    );
+    assert!(buf.is_empty());
+}
+
+#[test]
+fn decode_partial_frames() {
+    let mut codec = PilotOneCodec::new();
+    let mut buf = BytesMut::from(&b"{S01"[..]);
+    assert!(codec.decode(&mut buf).unwrap().is_none());
+    buf.extend_from_slice(b"****\r\n{M0");
+    let cmd = codec.decode(&mut buf).unwrap().unwrap();
+    assert_eq!(cmd.address, 0x01);
+    assert_eq!(cmd.data, None);
+    assert_eq!(&buf[..], b"{M0");
+    assert!(codec.decode(&mut buf).unwrap().is_none());
+}
+
+#[test]
+fn decode_invalid_frame() {
+    let mut codec = PilotOneCodec::new();
+    let mut buf = BytesMut::from(&b"{X01****\r\n{S01****\r\n"[..]);
+    assert!(codec.decode(&mut buf).is_err());
+    assert!(codec.decode(&mut buf).unwrap().is_some());
+}
+
+#[test]
+fn decode_garbage_without_delimiter() {
+    let mut codec = PilotOneCodec::new();
+    let mut buf = BytesMut::from(&b"{S01****{S01****"[..]);
+    assert!(codec.decode(&mut buf).is_err());
+    assert!(buf.is_empty());
+}
+
+#[test]
+fn encode_frame() {
+    let mut codec = PilotOneCodec::new();
+    let mut buf = BytesMut::new();
+    let cmd = Command {
+        sender: Master,
+        address: 0x09,
+        data: Some(0x05E8),
+    };
+    codec.encode(cmd, &mut buf).unwrap();
+    assert_eq!(&buf[..], b"{M0905E8\r\n");
+}
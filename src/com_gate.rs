@@ -0,0 +1,133 @@
+//! Payloads of the Com.G@te interface addresses.
+
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// Number of digital channels of a [`DigitalIo`] word.
+pub const DIGITAL_CHANNELS: u8 = 16;
+
+/// Digital inputs or outputs of the Com.G@te.
+///
+/// Each bit represents a channel, starting with
+/// channel `0` at the least significant bit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DigitalIo(u16);
+
+impl DigitalIo {
+    #[must_use]
+    pub const fn from_bits(bits: u16) -> Self {
+        Self(bits)
+    }
+
+    #[must_use]
+    pub const fn bits(self) -> u16 {
+        self.0
+    }
+
+    /// Returns `false` for channels that don't exist.
+    #[must_use]
+    pub const fn is_set(self, channel: u8) -> bool {
+        channel < DIGITAL_CHANNELS && self.0 & (1 << channel) != 0
+    }
+
+    /// Channels that don't exist are ignored.
+    #[must_use]
+    pub const fn with(self, channel: u8, state: bool) -> Self {
+        if channel >= DIGITAL_CHANNELS {
+            return self;
+        }
+        let mask = 1 << channel;
+        if state {
+            Self(self.0 | mask)
+        } else {
+            Self(self.0 & !mask)
+        }
+    }
+
+    /// Iterate over the states of all channels.
+    pub fn channels(self) -> impl Iterator<Item = bool> {
+        (0..DIGITAL_CHANNELS).map(move |channel| self.is_set(channel))
+    }
+}
+
+impl From<u16> for DigitalIo {
+    fn from(from: u16) -> Self {
+        Self::from_bits(from)
+    }
+}
+
+impl From<DigitalIo> for u16 {
+    fn from(from: DigitalIo) -> Self {
+        from.bits()
+    }
+}
+
+/// Formats the channels as bits, starting with
+/// the highest channel, e.g. `0000000000000101`.
+impl fmt::Display for DigitalIo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:016b}", self.0)
+    }
+}
+
+/// Current of the analog interface (0 to 20 mA) in µA.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AnalogSignal(u16);
+
+impl AnalogSignal {
+    /// Largest current of the analog interface (20 mA)
+    pub const MAX: Self = Self(20_000);
+
+    #[must_use]
+    pub const fn from_microamps(x: u16) -> Self {
+        Self(x)
+    }
+
+    #[must_use]
+    pub const fn microamps(self) -> u16 {
+        self.0
+    }
+
+    #[must_use]
+    pub fn milliamps(self) -> f64 {
+        f64::from(self.0) / 1000.0
+    }
+
+    #[must_use]
+    pub const fn from_data(x: u16) -> Self {
+        Self(x)
+    }
+
+    #[must_use]
+    pub const fn to_data(self) -> u16 {
+        self.0
+    }
+}
+
+impl From<u16> for AnalogSignal {
+    fn from(from: u16) -> Self {
+        Self::from_data(from)
+    }
+}
+
+impl From<AnalogSignal> for u16 {
+    fn from(from: AnalogSignal) -> Self {
+        from.to_data()
+    }
+}
+
+/// Formats the current in mA, e.g. `4.000 mA`.
+impl fmt::Display for AnalogSignal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{:03} mA", self.0 / 1000, self.0 % 1000)
+    }
+}
@@ -0,0 +1,49 @@
+use super::*;
+use crate::{Address, OnOff, Response};
+
+#[test]
+fn digital_channels() {
+    let io = DigitalIo::from_bits(0b101);
+    assert!(io.is_set(0));
+    assert!(!io.is_set(1));
+    assert!(io.is_set(2));
+    assert!(!io.is_set(16));
+    let io = io.with(1, true).with(0, false).with(20, true);
+    assert_eq!(io.bits(), 0b110);
+    assert_eq!(io.channels().filter(|state| *state).count(), 2);
+}
+
+#[test]
+fn analog_signal() {
+    let signal = AnalogSignal::from_data(4000);
+    assert_eq!(signal.microamps(), 4000);
+    assert!((signal.milliamps() - 4.0).abs() < f64::EPSILON);
+    assert!(signal <= AnalogSignal::MAX);
+}
+
+#[test]
+fn decode_com_gate_payloads() {
+    assert_eq!(
+        Response::from_data(Address::ComGateDigitalInputs, 0x0003),
+        Ok(Response::ComGateDigitalInputs(DigitalIo::from_bits(3)))
+    );
+    assert_eq!(
+        Response::from_data(Address::ComGateAnalogOutput, 20_000),
+        Ok(Response::ComGateAnalogOutput(AnalogSignal::MAX))
+    );
+    assert_eq!(
+        Response::from_data(Address::EcsStandby, 1),
+        Ok(Response::EcsStandby(OnOff::On))
+    );
+    assert!(Response::from_data(Address::EcsStandby, 2).is_err());
+    assert!(Address::ComGateAnalogOutput.validate_data(20_001).is_err());
+    assert!(!Address::ComGateDigitalInputs.is_writable());
+    assert!(Address::ComGateDigitalOutputs.is_writable());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn display_com_gate_payloads() {
+    assert_eq!(DigitalIo::from_bits(5).to_string(), "0000000000000101");
+    assert_eq!(AnalogSignal::from_microamps(4_020).to_string(), "4.020 mA");
+}
@@ -0,0 +1,249 @@
+//! Clients for [`embedded_io`] transports (e.g. a microcontroller UART).
+//!
+//! The blocking [`PilotOne`] is built on [`embedded_io`] and the
+//! [`AsyncPilotOne`] (feature `embedded-io-async`) on `embedded_io_async`,
+//! which makes it usable with async runtimes like Embassy.
+//! Everything in here works without `std` and without allocations.
+//!
+//! Unlike the `std` clients these clients don't pace the commands
+//! and don't time out, because there is no clock. The caller has to
+//! wait at least `DEFAULT_MIN_INTERVAL` (100 ms) between two exchanges
+//! and the transport has to provide a read timeout if needed.
+
+use core::fmt;
+
+use embedded_io::{Read, ReadExactError, Write};
+
+use crate::{
+    frame::FrameBuf, helpers::client_helpers, Command, DecodeError, Exchange, FrameFormat,
+    InvalidValue, OperationLock, ParseError, ResponseMismatch, ValidationError,
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Error<E> {
+    /// Transport error
+    Io(E),
+    /// The transport was closed
+    UnexpectedEof,
+    /// Invalid reply
+    Parse(ParseError),
+    /// Reply without data
+    MissingData,
+    /// Reply with invalid data
+    InvalidValue(InvalidValue),
+    /// Reply that can't be decoded
+    Decode(DecodeError),
+    /// Received frame is not the reply to the request
+    ResponseMismatch(ResponseMismatch),
+    /// The command was not sent because the device would reject it
+    Validation(ValidationError),
+}
+
+impl<E> From<ParseError> for Error<E> {
+    fn from(from: ParseError) -> Self {
+        Self::Parse(from)
+    }
+}
+
+impl<E> From<InvalidValue> for Error<E> {
+    fn from(from: InvalidValue) -> Self {
+        Self::InvalidValue(from)
+    }
+}
+
+impl<E> From<DecodeError> for Error<E> {
+    fn from(from: DecodeError) -> Self {
+        Self::Decode(from)
+    }
+}
+
+impl<E> From<ResponseMismatch> for Error<E> {
+    fn from(from: ResponseMismatch) -> Self {
+        Self::ResponseMismatch(from)
+    }
+}
+
+impl<E> From<ValidationError> for Error<E> {
+    fn from(from: ValidationError) -> Self {
+        Self::Validation(from)
+    }
+}
+
+impl<E> From<ReadExactError<E>> for Error<E> {
+    fn from(from: ReadExactError<E>) -> Self {
+        match from {
+            ReadExactError::UnexpectedEof => Self::UnexpectedEof,
+            ReadExactError::Other(e) => Self::Io(e),
+        }
+    }
+}
+
+impl<E: fmt::Debug> fmt::Display for Error<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "Transport error: {e:?}"),
+            Self::UnexpectedEof => write!(f, "Unexpected end of data"),
+            Self::Parse(e) => write!(f, "Invalid reply: {e}"),
+            Self::MissingData => write!(f, "Missing reply data"),
+            Self::InvalidValue(e) => write!(f, "Invalid reply: {e}"),
+            Self::Decode(e) => write!(f, "Invalid reply: {e}"),
+            Self::ResponseMismatch(e) => write!(f, "{e}"),
+            Self::Validation(e) => write!(f, "Invalid command: {e}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: fmt::Debug> std::error::Error for Error<E> {}
+
+fn confirm_lock<E>(actual: OperationLock, expected: OperationLock) -> Result<(), Error<E>> {
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(Error::InvalidValue(InvalidValue(actual.into())))
+    }
+}
+
+fn reply_data<E>(reply: Command) -> Result<u16, Error<E>> {
+    reply.data.ok_or(Error::MissingData)
+}
+
+/// Blocking Pilot ONE client for [`embedded_io`] transports.
+#[derive(Debug)]
+pub struct PilotOne<T> {
+    transport: T,
+    format: FrameFormat,
+}
+
+impl<T> PilotOne<T> {
+    #[must_use]
+    pub const fn new(transport: T) -> Self {
+        Self {
+            transport,
+            format: FrameFormat::Plain,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_frame_format(mut self, format: FrameFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    #[must_use]
+    pub const fn frame_format(&self) -> FrameFormat {
+        self.format
+    }
+
+    #[must_use]
+    pub const fn get_ref(&self) -> &T {
+        &self.transport
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.transport
+    }
+
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.transport
+    }
+}
+
+impl<T> PilotOne<T>
+where
+    T: Read + Write,
+{
+    /// Send a command and wait for the reply.
+    pub fn exchange(&mut self, cmd: Command) -> Result<Command, Error<T::Error>> {
+        let exchange = Exchange::new(cmd);
+        self.transport
+            .write_all(&self.format.encode(cmd))
+            .map_err(Error::Io)?;
+        self.transport.flush().map_err(Error::Io)?;
+        let mut frame = FrameBuf::new(self.format);
+        let mut byte = [0];
+        loop {
+            self.transport.read_exact(&mut byte)?;
+            if let Some(res) = frame.push(byte[0]) {
+                return Ok(exchange.verify(res?)?);
+            }
+        }
+    }
+
+    client_helpers!(Error<T::Error>);
+}
+
+/// Asynchronous Pilot ONE client for `embedded_io_async` transports.
+#[cfg(feature = "embedded-io-async")]
+#[derive(Debug)]
+pub struct AsyncPilotOne<T> {
+    transport: T,
+    format: FrameFormat,
+}
+
+#[cfg(feature = "embedded-io-async")]
+impl<T> AsyncPilotOne<T> {
+    #[must_use]
+    pub const fn new(transport: T) -> Self {
+        Self {
+            transport,
+            format: FrameFormat::Plain,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_frame_format(mut self, format: FrameFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    #[must_use]
+    pub const fn frame_format(&self) -> FrameFormat {
+        self.format
+    }
+
+    #[must_use]
+    pub const fn get_ref(&self) -> &T {
+        &self.transport
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.transport
+    }
+
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.transport
+    }
+}
+
+#[cfg(feature = "embedded-io-async")]
+impl<T> AsyncPilotOne<T>
+where
+    T: embedded_io_async::Read + embedded_io_async::Write,
+{
+    /// Send a command and wait for the reply.
+    pub async fn exchange(&mut self, cmd: Command) -> Result<Command, Error<T::Error>> {
+        let exchange = Exchange::new(cmd);
+        self.transport
+            .write_all(&self.format.encode(cmd))
+            .await
+            .map_err(Error::Io)?;
+        self.transport.flush().await.map_err(Error::Io)?;
+        let mut frame = FrameBuf::new(self.format);
+        let mut byte = [0];
+        loop {
+            self.transport.read_exact(&mut byte).await?;
+            if let Some(res) = frame.push(byte[0]) {
+                return Ok(exchange.verify(res?)?);
+            }
+        }
+    }
+
+    client_helpers!(Error<T::Error>, async.await);
+}
@@ -0,0 +1,123 @@
+use core::convert::Infallible;
+
+use embedded_io::{ErrorType, Read, Write};
+
+use super::*;
+use crate::{Address, Temperature};
+
+/// Transport that replays a fixed input and records the output.
+struct Mock {
+    rx: &'static [u8],
+    tx: [u8; 10],
+    tx_len: usize,
+}
+
+impl Mock {
+    const fn new(rx: &'static [u8]) -> Self {
+        Self {
+            rx,
+            tx: [0; 10],
+            tx_len: 0,
+        }
+    }
+}
+
+impl ErrorType for Mock {
+    type Error = Infallible;
+}
+
+impl Read for Mock {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Infallible> {
+        self.rx.read(buf)
+    }
+}
+
+impl Write for Mock {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Infallible> {
+        let len = buf.len().min(self.tx.len() - self.tx_len);
+        self.tx[self.tx_len..self.tx_len + len].copy_from_slice(&buf[..len]);
+        self.tx_len += len;
+        Ok(len)
+    }
+    fn flush(&mut self) -> Result<(), Infallible> {
+        Ok(())
+    }
+}
+
+#[test]
+fn get_internal_temp() {
+    let mut client = PilotOne::new(Mock::new(b"{S0109C4\r\n"));
+    let temp = client.get_internal_temp().unwrap();
+    assert_eq!(temp, Temperature::from_centi_celsius(2500));
+    assert_eq!(&client.get_ref().tx, b"{M01****\r\n");
+}
+
+#[test]
+fn set_setpoint() {
+    let mut client = PilotOne::new(Mock::new(b"{S00FF38\r\n"));
+    let temp = Temperature::from_centi_celsius(-200);
+    assert_eq!(client.set_setpoint(temp).unwrap(), temp);
+}
+
+#[test]
+fn reply_errors() {
+    let mut client = PilotOne::new(Mock::new(b"{S01****\r\n"));
+    let err = client.query(Address::InternalTemp).unwrap_err();
+    assert_eq!(err, Error::MissingData);
+
+    let mut client = PilotOne::new(Mock::new(b"{X01****\r\n"));
+    let err = client.query(Address::InternalTemp).unwrap_err();
+    assert_eq!(
+        err,
+        Error::Parse(ParseError::Sender {
+            offset: 1,
+            byte: b'X'
+        })
+    );
+
+    let mut client = PilotOne::new(Mock::new(b"{S01"));
+    let err = client.query(Address::InternalTemp).unwrap_err();
+    assert_eq!(err, Error::UnexpectedEof);
+}
+
+#[cfg(feature = "embedded-io-async")]
+impl embedded_io_async::Read for Mock {
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Infallible> {
+        Read::read(self, buf)
+    }
+}
+
+#[cfg(feature = "embedded-io-async")]
+impl embedded_io_async::Write for Mock {
+    async fn write(&mut self, buf: &[u8]) -> Result<usize, Infallible> {
+        Write::write(self, buf)
+    }
+}
+
+#[cfg(feature = "embedded-io-async")]
+#[tokio::test]
+async fn async_get_internal_temp() {
+    let mut client = AsyncPilotOne::new(Mock::new(b"{S0109C4\r\n"));
+    let temp = client.get_internal_temp().await.unwrap();
+    assert_eq!(temp, Temperature::from_centi_celsius(2500));
+    assert_eq!(&client.get_ref().tx, b"{M01****\r\n");
+}
+
+#[cfg(feature = "embedded-io-async")]
+#[tokio::test]
+async fn async_reply_errors() {
+    let mut client = AsyncPilotOne::new(Mock::new(b"{S01****\r\n"));
+    let err = client.query(Address::InternalTemp).await.unwrap_err();
+    assert_eq!(err, Error::MissingData);
+
+    let mut client = AsyncPilotOne::new(Mock::new(b"{S01"));
+    let err = client.query(Address::InternalTemp).await.unwrap_err();
+    assert_eq!(err, Error::UnexpectedEof);
+}
+
+#[test]
+fn response_mismatch() {
+    let mut client = PilotOne::new(Mock::new(b"{M01****\r\n"));
+    let err = client.query(Address::InternalTemp).unwrap_err();
+    assert!(matches!(err, Error::ResponseMismatch(_)));
+}
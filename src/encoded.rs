@@ -0,0 +1,145 @@
+use core::{fmt, ops::Deref, str};
+#[cfg(all(feature = "heapless", feature = "std"))]
+use std::io::{Error, ErrorKind};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Command, ParseError};
+
+#[cfg(test)]
+mod tests;
+
+/// Plain frame (including CRLF) of an encoded [`Command`].
+///
+/// The frame always consists of ASCII characters only,
+/// so it can be borrowed as `&str`. Raw bytes (e.g. when
+/// deserializing) are only accepted if they are a valid frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "[u8; 10]", into = "[u8; 10]"))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct EncodedCommand([u8; 10]);
+
+impl EncodedCommand {
+    #[must_use]
+    pub const fn new(cmd: Command) -> Self {
+        Self(cmd.into_bytes())
+    }
+
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8; 10] {
+        &self.0
+    }
+
+    #[must_use]
+    pub const fn into_bytes(self) -> [u8; 10] {
+        self.0
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        // All constructors guarantee ASCII characters.
+        str::from_utf8(&self.0).unwrap_or_default()
+    }
+}
+
+impl Command {
+    /// Encode the command as a plain frame.
+    #[must_use]
+    pub const fn encode(self) -> EncodedCommand {
+        EncodedCommand::new(self)
+    }
+}
+
+impl From<Command> for EncodedCommand {
+    fn from(from: Command) -> Self {
+        Self::new(from)
+    }
+}
+
+/// Accepts only frames that can be parsed.
+impl TryFrom<[u8; 10]> for EncodedCommand {
+    type Error = ParseError;
+    fn try_from(from: [u8; 10]) -> Result<Self, ParseError> {
+        let s = str::from_utf8(&from).map_err(|e| ParseError::NonAsciiStr {
+            offset: e.valid_up_to(),
+        })?;
+        let cmd = s.parse::<Command>()?;
+        Ok(Self::new(cmd))
+    }
+}
+
+impl From<EncodedCommand> for [u8; 10] {
+    fn from(from: EncodedCommand) -> Self {
+        from.into_bytes()
+    }
+}
+
+impl Deref for EncodedCommand {
+    type Target = [u8];
+    fn deref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for EncodedCommand {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsRef<str> for EncodedCommand {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Writes the frame including the trailing CRLF.
+impl fmt::Display for EncodedCommand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The string is too short to hold the frame.
+#[cfg(feature = "heapless")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CapacityError {
+    /// Capacity of the string
+    pub capacity: usize,
+}
+
+#[cfg(feature = "heapless")]
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Capacity of {} bytes is too small for a frame",
+            self.capacity
+        )
+    }
+}
+
+#[cfg(all(feature = "heapless", feature = "std"))]
+impl std::error::Error for CapacityError {}
+
+#[cfg(all(feature = "heapless", feature = "std"))]
+impl From<CapacityError> for Error {
+    fn from(e: CapacityError) -> Error {
+        Error::new(ErrorKind::InvalidInput, e)
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> TryFrom<EncodedCommand> for heapless::String<N> {
+    type Error = CapacityError;
+    fn try_from(from: EncodedCommand) -> Result<Self, CapacityError> {
+        let mut s = Self::new();
+        s.push_str(from.as_str())
+            .map_err(|()| CapacityError { capacity: N })?;
+        Ok(s)
+    }
+}
@@ -0,0 +1,76 @@
+use super::*;
+use crate::Address;
+
+#[test]
+fn encoded_command_as_str() {
+    let encoded = Command::set(Address::SetpointTempControl, 0x05E8).encode();
+    assert_eq!(encoded.as_str(), "{M0005E8\r\n");
+    assert_eq!(encoded.as_bytes(), b"{M0005E8\r\n");
+    let encoded = EncodedCommand::from(Command::query(Address::InternalTemp));
+    assert_eq!(AsRef::<str>::as_ref(&encoded), "{M01****\r\n");
+}
+
+#[test]
+fn encoded_command_is_ascii_for_all_addresses() {
+    for address in 0..=u8::MAX {
+        for data in [
+            None,
+            Some(0),
+            Some(u16::from(address) << 8 | 0xAB),
+            Some(u16::MAX),
+        ] {
+            let cmd = Command {
+                sender: crate::Sender::Slave,
+                address,
+                data,
+            };
+            let encoded = cmd.encode();
+            assert_eq!(encoded.as_str().as_bytes(), &cmd.into_bytes());
+            assert_eq!(encoded.as_str().parse::<Command>(), Ok(cmd));
+        }
+    }
+}
+
+#[test]
+fn encoded_command_from_bytes() {
+    let encoded = EncodedCommand::try_from(*b"{S0109C4\r\n").unwrap();
+    assert_eq!(encoded.as_str(), "{S0109C4\r\n");
+    assert_eq!(
+        EncodedCommand::try_from(*b"{S01\xFF9C4\r\n"),
+        Err(ParseError::NonAsciiStr { offset: 4 })
+    );
+    assert!(EncodedCommand::try_from(*b"{X0109C4\r\n").is_err());
+    assert!(EncodedCommand::try_from(*b"{s0109c4\r\n").is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn deserialize_encoded_command() {
+    let encoded = Command::query(Address::ProcessTemp).encode();
+    let json = serde_json::to_string(&encoded).unwrap();
+    assert_eq!(
+        serde_json::from_str::<EncodedCommand>(&json).unwrap(),
+        encoded
+    );
+    let invalid = serde_json::to_string(&[0xFF_u8; 10]).unwrap();
+    assert!(serde_json::from_str::<EncodedCommand>(&invalid).is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn display_encoded_command() {
+    let encoded = Command::query(Address::ProcessTemp).encode();
+    assert_eq!(encoded.to_string(), "{M07****\r\n");
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn encoded_command_into_heapless_string() {
+    let encoded = Command::query(Address::ProcessTemp).encode();
+    let s = heapless::String::<10>::try_from(encoded).unwrap();
+    assert_eq!(s.as_str(), "{M07****\r\n");
+    assert_eq!(
+        heapless::String::<9>::try_from(encoded),
+        Err(CapacityError { capacity: 9 })
+    );
+}
@@ -0,0 +1,110 @@
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Error code reported by [`Address::ErrorReport`](crate::Address::ErrorReport).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ErrorCode {
+    /// No error
+    NoError,
+    /// Over-temperature protection triggered
+    Overtemperature,
+    /// Low level of thermal fluid
+    LowLevel,
+    /// Internal temperature sensor defective
+    InternalSensor,
+    /// Process temperature sensor defective
+    ProcessSensor,
+    /// Pump motor overloaded
+    PumpOverload,
+    /// Compressor fault
+    Compressor,
+    /// Overpressure in the refrigeration circuit
+    Overpressure,
+    /// Heater fault
+    Heater,
+    /// Communication with the control unit interrupted
+    Communication,
+    /// Undocumented error code
+    Unknown(u16),
+}
+
+impl ErrorCode {
+    #[must_use]
+    pub const fn from_u16(x: u16) -> Self {
+        match x {
+            0 => Self::NoError,
+            1 => Self::Overtemperature,
+            2 => Self::LowLevel,
+            3 => Self::InternalSensor,
+            4 => Self::ProcessSensor,
+            5 => Self::PumpOverload,
+            6 => Self::Compressor,
+            7 => Self::Overpressure,
+            8 => Self::Heater,
+            9 => Self::Communication,
+            x => Self::Unknown(x),
+        }
+    }
+
+    #[must_use]
+    pub const fn code(self) -> u16 {
+        match self {
+            Self::NoError => 0,
+            Self::Overtemperature => 1,
+            Self::LowLevel => 2,
+            Self::InternalSensor => 3,
+            Self::ProcessSensor => 4,
+            Self::PumpOverload => 5,
+            Self::Compressor => 6,
+            Self::Overpressure => 7,
+            Self::Heater => 8,
+            Self::Communication => 9,
+            Self::Unknown(x) => x,
+        }
+    }
+
+    #[must_use]
+    pub const fn is_error(self) -> bool {
+        !matches!(self, Self::NoError)
+    }
+
+    /// Human-readable description.
+    #[must_use]
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::NoError => "No error",
+            Self::Overtemperature => "Over-temperature protection triggered",
+            Self::LowLevel => "Low level of thermal fluid",
+            Self::InternalSensor => "Internal temperature sensor defective",
+            Self::ProcessSensor => "Process temperature sensor defective",
+            Self::PumpOverload => "Pump motor overloaded",
+            Self::Compressor => "Compressor fault",
+            Self::Overpressure => "Overpressure in the refrigeration circuit",
+            Self::Heater => "Heater fault",
+            Self::Communication => "Communication with the control unit interrupted",
+            Self::Unknown(_) => "Unknown error",
+        }
+    }
+}
+
+impl From<u16> for ErrorCode {
+    fn from(from: u16) -> Self {
+        Self::from_u16(from)
+    }
+}
+
+impl From<ErrorCode> for u16 {
+    fn from(from: ErrorCode) -> Self {
+        from.code()
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (error {})", self.description(), self.code())
+    }
+}
@@ -0,0 +1,113 @@
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io::{Error, ErrorKind};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Command, Sender};
+
+/// Received frame that is not the reply to the sent command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ResponseMismatch {
+    pub request: Command,
+    pub response: Command,
+}
+
+impl fmt::Display for ResponseMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Response mismatch: expected reply from {:?} for address {:#04X}, got {:?} frame for address {:#04X}",
+            reply_sender(self.request.sender),
+            self.request.address,
+            self.response.sender,
+            self.response.address
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ResponseMismatch {}
+
+#[cfg(feature = "std")]
+impl From<ResponseMismatch> for Error {
+    fn from(e: ResponseMismatch) -> Error {
+        Error::new(ErrorKind::InvalidData, e)
+    }
+}
+
+/// Pairs a sent command with its reply.
+///
+/// A reply must come from the other side and echo the address of the request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Exchange {
+    request: Command,
+}
+
+impl Exchange {
+    #[must_use]
+    pub const fn new(request: Command) -> Self {
+        Self { request }
+    }
+
+    #[must_use]
+    pub const fn request(&self) -> Command {
+        self.request
+    }
+
+    #[must_use]
+    pub fn matches(&self, response: &Command) -> bool {
+        response.sender == reply_sender(self.request.sender)
+            && response.address == self.request.address
+    }
+
+    pub fn verify(&self, response: Command) -> Result<Command, ResponseMismatch> {
+        if self.matches(&response) {
+            Ok(response)
+        } else {
+            Err(ResponseMismatch {
+                request: self.request,
+                response,
+            })
+        }
+    }
+}
+
+const fn reply_sender(sender: Sender) -> Sender {
+    match sender {
+        Sender::Master => Sender::Slave,
+        Sender::Slave => Sender::Master,
+    }
+}
+
+/// Result of comparing the echo of a write with the requested data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SetOutcome {
+    /// The device echoed the requested data
+    Accepted,
+    /// The device echoed a different value, e.g. a limit
+    Clamped { actual: u16 },
+    /// The device replied without data
+    Rejected,
+}
+
+impl SetOutcome {
+    #[must_use]
+    pub const fn from_echo(requested: u16, echo: Option<u16>) -> Self {
+        match echo {
+            Some(actual) if actual == requested => Self::Accepted,
+            Some(actual) => Self::Clamped { actual },
+            None => Self::Rejected,
+        }
+    }
+
+    #[must_use]
+    pub const fn is_accepted(self) -> bool {
+        matches!(self, Self::Accepted)
+    }
+}
@@ -0,0 +1,213 @@
+//! C API for encoding and parsing PB frames.
+//!
+//! All types are `#[repr(C)]` so a header can be generated with
+//! [cbindgen](https://github.com/mozilla/cbindgen). To get a
+//! static or dynamic library build the crate with e.g.
+//! `cargo rustc --release --features ffi --crate-type staticlib`.
+
+use core::{ffi::c_char, ptr, slice, str};
+
+use crate::{Command, ParseError, Sender};
+
+#[cfg(test)]
+mod tests;
+
+/// Value of [`PilotOneCommand::sender`] for master commands.
+pub const PILOT_ONE_SENDER_MASTER: u8 = 0;
+
+/// Value of [`PilotOneCommand::sender`] for slave replies.
+pub const PILOT_ONE_SENDER_SLAVE: u8 = 1;
+
+/// Length of an encoded frame (including CRLF).
+pub const PILOT_ONE_FRAME_LEN: usize = 10;
+
+/// Plain representation of a [`Command`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PilotOneCommand {
+    /// [`PILOT_ONE_SENDER_MASTER`] or [`PILOT_ONE_SENDER_SLAVE`]
+    pub sender: u8,
+    pub address: u8,
+    /// `false` for queries (`****`)
+    pub has_data: bool,
+    /// Ignored if `has_data` is `false`
+    pub data: u16,
+}
+
+impl From<Command> for PilotOneCommand {
+    fn from(from: Command) -> Self {
+        let Command {
+            sender,
+            address,
+            data,
+        } = from;
+        Self {
+            sender: match sender {
+                Sender::Master => PILOT_ONE_SENDER_MASTER,
+                Sender::Slave => PILOT_ONE_SENDER_SLAVE,
+            },
+            address,
+            has_data: data.is_some(),
+            data: data.unwrap_or_default(),
+        }
+    }
+}
+
+impl TryFrom<PilotOneCommand> for Command {
+    type Error = PilotOneError;
+
+    fn try_from(from: PilotOneCommand) -> Result<Self, PilotOneError> {
+        let sender = match from.sender {
+            PILOT_ONE_SENDER_MASTER => Sender::Master,
+            PILOT_ONE_SENDER_SLAVE => Sender::Slave,
+            _ => return Err(PilotOneError::InvalidSender),
+        };
+        Ok(Self {
+            sender,
+            address: from.address,
+            data: from.has_data.then_some(from.data),
+        })
+    }
+}
+
+/// Result code of the C API.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PilotOneError {
+    Ok = 0,
+    /// A required pointer is null
+    NullPointer = 1,
+    /// The output buffer is too small
+    BufferTooSmall = 2,
+    /// [`PilotOneCommand::sender`] has an unknown value
+    InvalidSender = 3,
+    MessageLength = 10,
+    NonAsciiStr = 11,
+    Sender = 12,
+    CommandData = 13,
+    Address = 14,
+    StartByte = 15,
+    LineEnding = 16,
+    Checksum = 17,
+    Device = 18,
+}
+
+impl From<ParseError> for PilotOneError {
+    fn from(from: ParseError) -> Self {
+        match from {
+            ParseError::MessageLength { .. } => Self::MessageLength,
+            ParseError::NonAsciiStr { .. } => Self::NonAsciiStr,
+            ParseError::Sender { .. } => Self::Sender,
+            ParseError::CommandData { .. } => Self::CommandData,
+            ParseError::Address { .. } => Self::Address,
+            ParseError::StartByte { .. } => Self::StartByte,
+            ParseError::LineEnding { .. } => Self::LineEnding,
+            ParseError::Checksum { .. } => Self::Checksum,
+            ParseError::Device { .. } => Self::Device,
+        }
+    }
+}
+
+/// Encode `cmd` into `buf`.
+///
+/// On success the number of written bytes
+/// ([`PILOT_ONE_FRAME_LEN`]) is stored in `written`
+/// if it is not null.
+///
+/// # Safety
+///
+/// `cmd` must be null or point to a valid command and `buf`
+/// must be null or valid for writes of `len` bytes. `written`
+/// must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn pilot_one_encode(
+    cmd: *const PilotOneCommand,
+    buf: *mut u8,
+    len: usize,
+    written: *mut usize,
+) -> PilotOneError {
+    // SAFETY: guaranteed by the caller
+    let Some(cmd) = (unsafe { cmd.as_ref() }) else {
+        return PilotOneError::NullPointer;
+    };
+    if buf.is_null() {
+        return PilotOneError::NullPointer;
+    }
+    let cmd = match Command::try_from(*cmd) {
+        Ok(cmd) => cmd,
+        Err(err) => return err,
+    };
+    if len < PILOT_ONE_FRAME_LEN {
+        return PilotOneError::BufferTooSmall;
+    }
+    let bytes = cmd.into_bytes();
+    // SAFETY: `buf` is valid for at least `PILOT_ONE_FRAME_LEN` bytes
+    // and cannot overlap with the local array.
+    unsafe {
+        ptr::copy_nonoverlapping(bytes.as_ptr(), buf, bytes.len());
+    }
+    // SAFETY: guaranteed by the caller
+    if let Some(written) = unsafe { written.as_mut() } {
+        *written = bytes.len();
+    }
+    PilotOneError::Ok
+}
+
+/// Parse a complete frame (including CRLF) of `len` bytes.
+///
+/// # Safety
+///
+/// `buf` must be null or valid for reads of `len` bytes and
+/// `cmd` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn pilot_one_parse(
+    buf: *const u8,
+    len: usize,
+    cmd: *mut PilotOneCommand,
+) -> PilotOneError {
+    if buf.is_null() {
+        return PilotOneError::NullPointer;
+    }
+    // SAFETY: guaranteed by the caller
+    let Some(cmd) = (unsafe { cmd.as_mut() }) else {
+        return PilotOneError::NullPointer;
+    };
+    // SAFETY: guaranteed by the caller
+    let bytes = unsafe { slice::from_raw_parts(buf, len) };
+    let parsed = str::from_utf8(bytes)
+        .map_err(|e| ParseError::NonAsciiStr {
+            offset: e.valid_up_to(),
+        })
+        .and_then(str::parse::<Command>);
+    match parsed {
+        Ok(parsed) => {
+            *cmd = parsed.into();
+            PilotOneError::Ok
+        }
+        Err(err) => err.into(),
+    }
+}
+
+/// Static, NUL-terminated description of an error code.
+///
+/// Unknown codes return `"Unknown error"`.
+#[no_mangle]
+pub const extern "C" fn pilot_one_error_message(code: i32) -> *const c_char {
+    let msg = match code {
+        0 => c"Ok",
+        1 => c"Null pointer",
+        2 => c"Buffer too small",
+        3 => c"Unknown sender value",
+        10 => c"Invalid message length",
+        11 => c"Non-ASCII character",
+        12 => c"Invalid sender",
+        13 => c"Invalid command data",
+        14 => c"Invalid command address",
+        15 => c"Missing start character",
+        16 => c"Missing line ending",
+        17 => c"Invalid checksum",
+        18 => c"Invalid device address",
+        _ => c"Unknown error",
+    };
+    msg.as_ptr()
+}
@@ -0,0 +1,59 @@
+use core::ffi::CStr;
+
+use super::*;
+use crate::Address;
+
+#[test]
+fn encode_command() {
+    let cmd = PilotOneCommand::from(Command::set(Address::SetProcessTemp, 0x05E8));
+    let mut buf = [0; 16];
+    let mut written = 0;
+    let res = unsafe { pilot_one_encode(&cmd, buf.as_mut_ptr(), buf.len(), &mut written) };
+    assert_eq!(res, PilotOneError::Ok);
+    assert_eq!(&buf[..written], b"{M0905E8\r\n");
+
+    let res = unsafe { pilot_one_encode(&cmd, buf.as_mut_ptr(), 9, ptr::null_mut()) };
+    assert_eq!(res, PilotOneError::BufferTooSmall);
+    let res = unsafe { pilot_one_encode(ptr::null(), buf.as_mut_ptr(), 16, ptr::null_mut()) };
+    assert_eq!(res, PilotOneError::NullPointer);
+
+    let cmd = PilotOneCommand { sender: 2, ..cmd };
+    let res = unsafe { pilot_one_encode(&cmd, buf.as_mut_ptr(), 16, ptr::null_mut()) };
+    assert_eq!(res, PilotOneError::InvalidSender);
+}
+
+#[test]
+fn parse_command() {
+    let frame = b"{S0109C4\r\n";
+    let mut cmd = PilotOneCommand::from(Command::query(Address::SetpointTempControl));
+    let res = unsafe { pilot_one_parse(frame.as_ptr(), frame.len(), &mut cmd) };
+    assert_eq!(res, PilotOneError::Ok);
+    assert_eq!(
+        cmd,
+        PilotOneCommand {
+            sender: PILOT_ONE_SENDER_SLAVE,
+            address: 0x01,
+            has_data: true,
+            data: 0x09C4
+        }
+    );
+
+    let frame = b"{M01****\r\n";
+    let res = unsafe { pilot_one_parse(frame.as_ptr(), frame.len(), &mut cmd) };
+    assert_eq!(res, PilotOneError::Ok);
+    assert!(!cmd.has_data);
+
+    let frame = b"{X01****\r\n";
+    let res = unsafe { pilot_one_parse(frame.as_ptr(), frame.len(), &mut cmd) };
+    assert_eq!(res, PilotOneError::Sender);
+    let res = unsafe { pilot_one_parse(frame.as_ptr(), frame.len(), ptr::null_mut()) };
+    assert_eq!(res, PilotOneError::NullPointer);
+}
+
+#[test]
+fn error_messages() {
+    let msg = |code| unsafe { CStr::from_ptr(pilot_one_error_message(code)) };
+    assert_eq!(msg(PilotOneError::Ok as i32), c"Ok");
+    assert_eq!(msg(PilotOneError::Checksum as i32), c"Invalid checksum");
+    assert_eq!(msg(-1), c"Unknown error");
+}
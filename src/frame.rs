@@ -0,0 +1,75 @@
+use core::str;
+
+use crate::{Command, FrameFormat, ParseError};
+
+/// Maximum number of bytes of a single frame.
+pub(crate) const MAX_FRAME_LEN: usize = 12;
+
+/// Assembles a frame from single bytes.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct FrameBuf {
+    format: FrameFormat,
+    buf: [u8; MAX_FRAME_LEN],
+    len: usize,
+    /// Length of the last complete or dropped frame.
+    last_len: usize,
+}
+
+impl FrameBuf {
+    pub(crate) const fn new(format: FrameFormat) -> Self {
+        Self {
+            format,
+            buf: [0; MAX_FRAME_LEN],
+            len: 0,
+            last_len: 0,
+        }
+    }
+
+    /// Raw bytes of the frame that has been returned by [`Self::push`].
+    #[cfg(feature = "tracing")]
+    pub(crate) fn last_frame(&self) -> &[u8] {
+        &self.buf[..self.last_len]
+    }
+
+    /// Returns the parsed command as soon as the frame is complete.
+    ///
+    /// A start character in the middle of a frame drops the
+    /// incomplete frame and starts a new one.
+    pub(crate) fn push(&mut self, byte: u8) -> Option<Result<Command, ParseError>> {
+        if byte == b'{' && self.len > 0 {
+            let len = self.len;
+            self.last_len = len;
+            // The start character is already in place.
+            self.len = 1;
+            return Some(Err(ParseError::MessageLength { len }));
+        }
+        // Skip everything in front of the start character.
+        if self.len == 0 && byte != b'{' {
+            return None;
+        }
+        if self.len == self.format.frame_len() {
+            // The overflowing byte is not a start character,
+            // so it is skipped like any other noise.
+            self.last_len = self.len;
+            self.len = 0;
+            return Some(Err(ParseError::MessageLength {
+                len: self.format.frame_len() + 1,
+            }));
+        }
+        self.buf[self.len] = byte;
+        self.len += 1;
+        if byte != b'\n' {
+            return None;
+        }
+        let frame = &self.buf[..self.len];
+        self.last_len = self.len;
+        self.len = 0;
+        Some(
+            str::from_utf8(frame)
+                .map_err(|e| ParseError::NonAsciiStr {
+                    offset: e.valid_up_to(),
+                })
+                .and_then(|s| self.format.decode(s)),
+        )
+    }
+}
@@ -0,0 +1,298 @@
+//! Typed helpers shared by all clients.
+//!
+//! The clients only differ in their transport, their error type and
+//! whether they are async. Each client implements `exchange` and
+//! invokes [`client_helpers!`] in the same `impl` block. The functions
+//! `reply_data` and `confirm_lock` have to be in scope at the
+//! invocation site.
+//!
+//! The typed setters validate their value with
+//! [`Address::validate_data`](crate::Address::validate_data)
+//! before sending a command.
+
+/// Expands to the typed helpers of a client.
+///
+/// `$err` is the error type of the client. Async clients
+/// additionally pass `async.await`.
+macro_rules! client_helpers {
+    ($err:ty $(, $async:ident . $await:ident)?) => {
+        /// Read the current value of `address`.
+        pub $($async)? fn query(
+            &mut self,
+            address: $crate::Address,
+        ) -> ::core::result::Result<u16, $err> {
+            let reply = self.exchange($crate::Command::query(address))$(.$await)??;
+            reply_data(reply)
+        }
+
+        /// Set `address` to `data` and return the value echoed by the device.
+        pub $($async)? fn set(
+            &mut self,
+            address: $crate::Address,
+            data: u16,
+        ) -> ::core::result::Result<u16, $err> {
+            let reply = self.exchange($crate::Command::set(address, data))$(.$await)??;
+            reply_data(reply)
+        }
+
+        /// Set `address` to `data` and compare the echo with `data`.
+        ///
+        /// Unlike [`Self::set`] a reply without data is
+        /// reported as [`SetOutcome::Rejected`]($crate::SetOutcome::Rejected).
+        pub $($async)? fn set_verified(
+            &mut self,
+            address: $crate::Address,
+            data: u16,
+        ) -> ::core::result::Result<$crate::SetOutcome, $err> {
+            let reply = self.exchange($crate::Command::set(address, data))$(.$await)??;
+            Ok($crate::SetOutcome::from_echo(data, reply.data))
+        }
+
+        pub $($async)? fn get_setpoint(
+            &mut self,
+        ) -> ::core::result::Result<$crate::Temperature, $err> {
+            self.query($crate::Address::SetpointTempControl)$(.$await)?
+                .map($crate::Temperature::from)
+        }
+
+        /// Fails without sending a command if `temp` is
+        /// outside of [`SETPOINT_RANGE`]($crate::SETPOINT_RANGE).
+        pub $($async)? fn set_setpoint(
+            &mut self,
+            temp: $crate::Temperature,
+        ) -> ::core::result::Result<$crate::Temperature, $err> {
+            $crate::Address::SetpointTempControl.validate_data(temp.into())?;
+            self.set($crate::Address::SetpointTempControl, temp.into())$(.$await)?
+                .map($crate::Temperature::from)
+        }
+
+        pub $($async)? fn get_alarm_limit(
+            &mut self,
+            limit: $crate::AlarmLimit,
+        ) -> ::core::result::Result<$crate::Temperature, $err> {
+            self.query(limit.address())$(.$await)?
+                .map($crate::Temperature::from)
+        }
+
+        /// Fails without sending a command if `temp` is
+        /// outside of [`AlarmLimit::range`]($crate::AlarmLimit::range).
+        pub $($async)? fn set_alarm_limit(
+            &mut self,
+            limit: $crate::AlarmLimit,
+            temp: $crate::Temperature,
+        ) -> ::core::result::Result<$crate::Temperature, $err> {
+            limit.address().validate_data(temp.into())?;
+            self.set(limit.address(), temp.into())$(.$await)?
+                .map($crate::Temperature::from)
+        }
+
+        pub $($async)? fn get_internal_temp(
+            &mut self,
+        ) -> ::core::result::Result<$crate::Temperature, $err> {
+            self.query($crate::Address::InternalTemp)$(.$await)?
+                .map($crate::Temperature::from)
+        }
+
+        pub $($async)? fn get_pump_pressure(
+            &mut self,
+        ) -> ::core::result::Result<$crate::Pressure, $err> {
+            self.query($crate::Address::PumpPressure)$(.$await)?
+                .map($crate::Pressure::from)
+        }
+
+        pub $($async)? fn get_power(&mut self) -> ::core::result::Result<$crate::Power, $err> {
+            self.query($crate::Address::CurrentPower)$(.$await)?
+                .map($crate::Power::from)
+        }
+
+        pub $($async)? fn get_pump_speed(
+            &mut self,
+        ) -> ::core::result::Result<$crate::PumpSpeed, $err> {
+            self.query($crate::Address::PumpSpeed)$(.$await)?
+                .map($crate::PumpSpeed::from)
+        }
+
+        pub $($async)? fn get_pump_speed_setpoint(
+            &mut self,
+        ) -> ::core::result::Result<$crate::PumpSpeed, $err> {
+            self.query($crate::Address::PumpSpeedSetpoint)$(.$await)?
+                .map($crate::PumpSpeed::from)
+        }
+
+        pub $($async)? fn set_pump_speed_setpoint(
+            &mut self,
+            speed: $crate::PumpSpeed,
+        ) -> ::core::result::Result<$crate::PumpSpeed, $err> {
+            $crate::Address::PumpSpeedSetpoint.validate_data(speed.into())?;
+            self.set($crate::Address::PumpSpeedSetpoint, speed.into())$(.$await)?
+                .map($crate::PumpSpeed::from)
+        }
+
+        pub $($async)? fn get_digital_inputs(
+            &mut self,
+        ) -> ::core::result::Result<$crate::DigitalIo, $err> {
+            self.query($crate::Address::ComGateDigitalInputs)$(.$await)?
+                .map($crate::DigitalIo::from)
+        }
+
+        pub $($async)? fn get_digital_outputs(
+            &mut self,
+        ) -> ::core::result::Result<$crate::DigitalIo, $err> {
+            self.query($crate::Address::ComGateDigitalOutputs)$(.$await)?
+                .map($crate::DigitalIo::from)
+        }
+
+        pub $($async)? fn get_analog_input(
+            &mut self,
+        ) -> ::core::result::Result<$crate::AnalogSignal, $err> {
+            self.query($crate::Address::ComGateAnalogInput)$(.$await)?
+                .map($crate::AnalogSignal::from)
+        }
+
+        pub $($async)? fn set_digital_outputs(
+            &mut self,
+            outputs: $crate::DigitalIo,
+        ) -> ::core::result::Result<$crate::DigitalIo, $err> {
+            $crate::Address::ComGateDigitalOutputs.validate_data(outputs.into())?;
+            self.set($crate::Address::ComGateDigitalOutputs, outputs.into())$(.$await)?
+                .map($crate::DigitalIo::from)
+        }
+
+        /// Fails without sending a command if `signal`
+        /// exceeds [`AnalogSignal::MAX`]($crate::AnalogSignal::MAX).
+        pub $($async)? fn set_analog_output(
+            &mut self,
+            signal: $crate::AnalogSignal,
+        ) -> ::core::result::Result<$crate::AnalogSignal, $err> {
+            $crate::Address::ComGateAnalogOutput.validate_data(signal.into())?;
+            self.set($crate::Address::ComGateAnalogOutput, signal.into())$(.$await)?
+                .map($crate::AnalogSignal::from)
+        }
+
+        pub $($async)? fn get_ecs_standby(&mut self) -> ::core::result::Result<$crate::OnOff, $err> {
+            Ok(self.query($crate::Address::EcsStandby)$(.$await)??.try_into()?)
+        }
+
+        pub $($async)? fn get_firmware_version(
+            &mut self,
+        ) -> ::core::result::Result<$crate::FirmwareVersion, $err> {
+            self.query($crate::Address::FirmwareVersion)$(.$await)?
+                .map($crate::FirmwareVersion::from)
+        }
+
+        /// Query the type, firmware version and serial number of the unit.
+        pub $($async)? fn identify(&mut self) -> ::core::result::Result<$crate::DeviceInfo, $err> {
+            let device_type = self.query($crate::Address::DeviceType)$(.$await)??;
+            let firmware_version = self.get_firmware_version()$(.$await)??;
+            let high = self.query($crate::Address::SerialNumberHigh)$(.$await)??;
+            let low = self.query($crate::Address::SerialNumberLow)$(.$await)??;
+            Ok($crate::DeviceInfo {
+                device_type,
+                firmware_version,
+                serial_number: $crate::DeviceInfo::serial_number_from_data(high, low),
+            })
+        }
+
+        pub $($async)? fn get_status(
+            &mut self,
+        ) -> ::core::result::Result<$crate::StatusFlags, $err> {
+            self.query($crate::Address::Status)$(.$await)?
+                .map($crate::StatusFlags::from)
+        }
+
+        pub $($async)? fn get_error_code(
+            &mut self,
+        ) -> ::core::result::Result<$crate::ErrorCode, $err> {
+            self.query($crate::Address::ErrorReport)$(.$await)?
+                .map($crate::ErrorCode::from)
+        }
+
+        pub $($async)? fn get_warning_code(
+            &mut self,
+        ) -> ::core::result::Result<$crate::WarningCode, $err> {
+            self.query($crate::Address::WarningMessage)$(.$await)?
+                .map($crate::WarningCode::from)
+        }
+
+        pub $($async)? fn get_temp_control_mode(
+            &mut self,
+        ) -> ::core::result::Result<$crate::TempControlMode, $err> {
+            Ok(self.query($crate::Address::TempControlMode)$(.$await)??.try_into()?)
+        }
+
+        pub $($async)? fn set_temp_control_mode(
+            &mut self,
+            mode: $crate::TempControlMode,
+        ) -> ::core::result::Result<$crate::TempControlMode, $err> {
+            $crate::Address::TempControlMode.validate_data(mode.into())?;
+            let reply = self
+                .exchange($crate::Command::set_temp_control_mode(mode))$(.$await)??;
+            Ok(reply_data(reply)?.try_into()?)
+        }
+
+        /// Read the current state of a boolean `address`.
+        pub $($async)? fn get_switch(
+            &mut self,
+            address: $crate::Address,
+        ) -> ::core::result::Result<$crate::OnOff, $err> {
+            Ok(self.query(address)$(.$await)??.try_into()?)
+        }
+
+        /// Switch a boolean `address` on or off.
+        pub $($async)? fn set_switch(
+            &mut self,
+            address: $crate::Address,
+            state: $crate::OnOff,
+        ) -> ::core::result::Result<$crate::OnOff, $err> {
+            address.validate_data(state.into())?;
+            let reply = self
+                .exchange($crate::Command::set_switch(address, state))$(.$await)??;
+            Ok(reply_data(reply)?.try_into()?)
+        }
+
+        pub $($async)? fn get_operation_lock(
+            &mut self,
+        ) -> ::core::result::Result<$crate::OperationLock, $err> {
+            Ok(self.query($crate::Address::OperationLock)$(.$await)??.try_into()?)
+        }
+
+        pub $($async)? fn set_operation_lock(
+            &mut self,
+            lock: $crate::OperationLock,
+        ) -> ::core::result::Result<$crate::OperationLock, $err> {
+            $crate::Address::OperationLock.validate_data(lock.into())?;
+            let reply = self
+                .exchange($crate::Command::set_operation_lock(lock))$(.$await)??;
+            Ok(reply_data(reply)?.try_into()?)
+        }
+
+        /// Lock the front panel of the device.
+        ///
+        /// Fails if the device does not confirm the lock.
+        pub $($async)? fn lock_operation(&mut self) -> ::core::result::Result<(), $err> {
+            let lock = self
+                .set_operation_lock($crate::OperationLock::Locked)$(.$await)??;
+            confirm_lock(lock, $crate::OperationLock::Locked)
+        }
+
+        /// Unlock the front panel of the device.
+        ///
+        /// Fails if the device does not confirm the unlock.
+        pub $($async)? fn unlock_operation(&mut self) -> ::core::result::Result<(), $err> {
+            let lock = self
+                .set_operation_lock($crate::OperationLock::Unlocked)$(.$await)??;
+            confirm_lock(lock, $crate::OperationLock::Unlocked)
+        }
+
+        /// Read and decode the current value of `address`.
+        pub $($async)? fn read(
+            &mut self,
+            address: $crate::Address,
+        ) -> ::core::result::Result<$crate::Response, $err> {
+            let reply = self.exchange($crate::Command::query(address))$(.$await)??;
+            Ok($crate::Response::decode(&reply)?)
+        }
+    };
+}
+
+pub(crate) use client_helpers;
@@ -0,0 +1,81 @@
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Firmware version of the controller.
+///
+/// The major version is transmitted in the high byte
+/// and the minor version in the low byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct FirmwareVersion {
+    pub major: u8,
+    pub minor: u8,
+}
+
+impl FirmwareVersion {
+    #[must_use]
+    pub const fn new(major: u8, minor: u8) -> Self {
+        Self { major, minor }
+    }
+
+    #[must_use]
+    pub const fn from_data(x: u16) -> Self {
+        let [major, minor] = x.to_be_bytes();
+        Self { major, minor }
+    }
+
+    #[must_use]
+    pub const fn to_data(self) -> u16 {
+        u16::from_be_bytes([self.major, self.minor])
+    }
+}
+
+impl From<u16> for FirmwareVersion {
+    fn from(from: u16) -> Self {
+        Self::from_data(from)
+    }
+}
+
+impl From<FirmwareVersion> for u16 {
+    fn from(from: FirmwareVersion) -> Self {
+        from.to_data()
+    }
+}
+
+impl fmt::Display for FirmwareVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{:02}", self.major, self.minor)
+    }
+}
+
+/// Identification of the connected unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DeviceInfo {
+    /// Type code of the unit
+    pub device_type: u16,
+    pub firmware_version: FirmwareVersion,
+    pub serial_number: u32,
+}
+
+impl DeviceInfo {
+    /// Combine the two words of the serial number.
+    #[must_use]
+    pub const fn serial_number_from_data(high: u16, low: u16) -> u32 {
+        (high as u32) << 16 | low as u32
+    }
+}
+
+impl fmt::Display for DeviceInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "type {:#06X}, firmware {}, serial number {}",
+            self.device_type, self.firmware_version, self.serial_number
+        )
+    }
+}
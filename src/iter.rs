@@ -0,0 +1,49 @@
+use crate::{frame::FrameBuf, Command, FrameFormat, ParseError};
+
+#[cfg(test)]
+mod tests;
+
+/// Iterator over the frames of a byte buffer.
+///
+/// Bytes between frames are skipped and an
+/// incomplete frame at the end is ignored.
+#[derive(Debug, Clone)]
+pub struct CommandIter<'a> {
+    bytes: &'a [u8],
+    frame: FrameBuf,
+}
+
+impl<'a> CommandIter<'a> {
+    #[must_use]
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self::with_frame_format(bytes, FrameFormat::Plain)
+    }
+
+    #[must_use]
+    pub const fn with_frame_format(bytes: &'a [u8], format: FrameFormat) -> Self {
+        Self {
+            bytes,
+            frame: FrameBuf::new(format),
+        }
+    }
+
+    /// Bytes that have not been processed yet.
+    #[must_use]
+    pub const fn remaining(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl Iterator for CommandIter<'_> {
+    type Item = Result<Command, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((byte, tail)) = self.bytes.split_first() {
+            self.bytes = tail;
+            if let Some(res) = self.frame.push(*byte) {
+                return Some(res);
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,53 @@
+use super::*;
+use crate::Address;
+
+#[test]
+fn split_frames() {
+    let mut iter = CommandIter::new(b"{M01****\r\n{S0109C4\r\n");
+    assert_eq!(iter.next(), Some(Ok(Command::query(Address::InternalTemp))));
+    assert_eq!(iter.next().unwrap().unwrap().data, Some(0x09C4));
+    assert_eq!(iter.next(), None);
+    assert!(iter.remaining().is_empty());
+}
+
+#[test]
+fn skip_noise_between_frames() {
+    let mut iter = CommandIter::new(b"\0\r\n{M01****\r\n*#\n{M02****\r\n{M0");
+    assert_eq!(iter.next().unwrap().unwrap().address, 0x01);
+    assert_eq!(iter.next().unwrap().unwrap().address, 0x02);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn continue_after_invalid_frame() {
+    let mut iter = CommandIter::new(b"{X01****\r\n{M01****\r\n");
+    assert_eq!(
+        iter.next(),
+        Some(Err(ParseError::Sender {
+            offset: 1,
+            byte: b'X'
+        }))
+    );
+    assert_eq!(iter.next(), Some(Ok(Command::query(Address::InternalTemp))));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn restart_at_start_character() {
+    let mut iter = CommandIter::new(b"{S0109{M01****\r\n{M01*****\r\n{M02****\r\n");
+    assert_eq!(iter.next(), Some(Err(ParseError::MessageLength { len: 6 })));
+    assert_eq!(iter.next(), Some(Ok(Command::query(Address::InternalTemp))));
+    assert_eq!(
+        iter.next(),
+        Some(Err(ParseError::MessageLength { len: 11 }))
+    );
+    assert_eq!(iter.next().unwrap().unwrap().address, 0x02);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn split_frames_with_checksum() {
+    let mut iter = CommandIter::with_frame_format(b"{M01****D1\r\n", FrameFormat::Checksum);
+    assert_eq!(iter.next(), Some(Ok(Command::query(Address::InternalTemp))));
+    assert_eq!(iter.next(), None);
+}
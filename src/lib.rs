@@ -2,17 +2,124 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 #[cfg(feature = "std")]
-use std::{
-    fmt,
-    io::{Error, ErrorKind},
+use std::io::{Error, ErrorKind};
+
+use core::{fmt, str::FromStr};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+mod addressed;
+mod alarm;
+mod batch;
+mod builder;
+mod centi;
+mod checksum;
+mod com_gate;
+mod encoded;
+mod error_code;
+mod exchange;
+mod frame;
+mod identity;
+mod iter;
+mod push;
+mod quantity;
+mod response;
+mod status;
+mod temperature;
+mod typed;
+mod validate;
+mod value;
+mod warning_code;
+
+pub use self::{
+    addressed::AddressedCommand,
+    alarm::AlarmLimit,
+    batch::{encode_batch, BufferOverflow},
+    builder::{BuildError, CommandBuilder},
+    checksum::{checksum, Frame, FrameFormat},
+    com_gate::{AnalogSignal, DigitalIo, DIGITAL_CHANNELS},
+    encoded::EncodedCommand,
+    error_code::ErrorCode,
+    exchange::{Exchange, ResponseMismatch, SetOutcome},
+    identity::{DeviceInfo, FirmwareVersion},
+    iter::CommandIter,
+    push::{ParseEvent, PushParser},
+    quantity::{Power, Pressure, PumpSpeed},
+    response::{DecodeError, Response},
+    status::StatusFlags,
+    temperature::Temperature,
+    typed::{CommandAddress, TypedCommand, UnknownAddress},
+    validate::{ValidationError, DELTA_LIMIT_RANGE, SETPOINT_RANGE},
+    value::{InvalidValue, OnOff, OperationLock, TempControlMode},
+    warning_code::{Severity, WarningCode},
+};
+
+pub mod package;
+pub mod ramp;
+pub mod simulator;
+
+#[cfg(any(feature = "std", feature = "embedded-io"))]
+mod helpers;
+
+#[cfg(feature = "std")]
+mod client;
+#[cfg(feature = "std")]
+pub mod poller;
+#[cfg(feature = "std")]
+mod rate_limit;
+#[cfg(feature = "std")]
+mod retry;
+#[cfg(feature = "std")]
+mod state;
+#[cfg(feature = "std")]
+mod thermostat;
+#[cfg(feature = "std")]
+pub mod trace;
+
+#[cfg(feature = "embedded-io")]
+pub mod embedded;
+
+#[cfg(feature = "std")]
+pub use self::{
+    client::PilotOne,
+    rate_limit::DEFAULT_MIN_INTERVAL,
+    retry::{RetryPolicy, TimeoutError, Timeouts},
+    state::{DeviceState, Sample},
+    thermostat::Thermostat,
 };
 
-use core::str::FromStr;
+#[cfg(feature = "modbus")]
+pub mod modbus;
+
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+#[cfg(feature = "serialport")]
+pub mod serial;
+#[cfg(feature = "tcp")]
+pub mod tcp;
 
+#[cfg(feature = "tokio")]
+mod async_client;
+#[cfg(feature = "tokio")]
+mod codec;
+
+#[cfg(feature = "tokio")]
+pub use self::{async_client::AsyncPilotOne, codec::PilotOneCodec};
+
+#[cfg(feature = "heapless")]
+pub use self::encoded::CapacityError;
+
+#[cfg(all(test, feature = "std"))]
+mod mock;
 #[cfg(test)]
 mod tests;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Command {
     pub sender: Sender,
     pub address: u8,
@@ -20,12 +127,18 @@ pub struct Command {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Sender {
     Master,
     Slave,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Address {
     /// Setpoint temperature controller
     SetpointTempControl = 0x00,
@@ -33,26 +146,89 @@ pub enum Address {
     /// Internal temperature
     InternalTemp = 0x01,
 
+    /// Pump pressure (relative)
+    PumpPressure = 0x03,
+
+    /// Current power
+    CurrentPower = 0x04,
+
     /// Error report
     ErrorReport = 0x05,
 
     /// Warning message
     WarningMessage = 0x06,
 
+    /// Process temperature
+    ProcessTemp = 0x07,
+
     /// Setting process temperature
     SetProcessTemp = 0x09,
 
+    /// Status word
+    Status = 0x0A,
+
     /// Temperature control mode
     TempControlMode = 0x13,
 
     /// Temperature control
     TempControl = 0x14,
 
+    /// Circulation
+    Circulation = 0x16,
+
     /// Operating lock
     OperationLock = 0x17,
 
+    /// Degassing
+    Degassing = 0x18,
+
     /// Process temperature actual value setting mode
     ProcessTempActualSettingMode = 0x19,
+
+    /// Over-temperature alarm limit
+    UpperAlarmLimit = 0x1B,
+
+    /// Under-temperature alarm limit
+    LowerAlarmLimit = 0x1C,
+
+    /// Upper limit of the deviation from the setpoint
+    UpperDeltaLimit = 0x1D,
+
+    /// Lower limit of the deviation from the setpoint
+    LowerDeltaLimit = 0x1E,
+
+    /// Actual pump speed
+    PumpSpeed = 0x26,
+
+    /// Pump speed setpoint
+    PumpSpeedSetpoint = 0x48,
+
+    /// Digital inputs of the Com.G@te
+    ComGateDigitalInputs = 0x5A,
+
+    /// Digital outputs of the Com.G@te
+    ComGateDigitalOutputs = 0x5B,
+
+    /// Analog interface input of the Com.G@te
+    ComGateAnalogInput = 0x5C,
+
+    /// Analog interface output of the Com.G@te
+    ComGateAnalogOutput = 0x5D,
+
+    /// ECS standby contact of the Com.G@te
+    EcsStandby = 0x5E,
+
+    /// Type code of the unit
+    DeviceType = 0x6A,
+
+    /// Firmware version of the controller
+    FirmwareVersion = 0x6B,
+
+    /// Serial number (high word)
+    SerialNumberHigh = 0x6C,
+
+    /// Serial number (low word)
+    SerialNumberLow = 0x6D,
 }
 
 impl Address {
@@ -61,13 +237,34 @@ impl Address {
         match x {
             0x00 => Some(Self::SetpointTempControl),
             0x01 => Some(Self::InternalTemp),
+            0x03 => Some(Self::PumpPressure),
+            0x04 => Some(Self::CurrentPower),
             0x05 => Some(Self::ErrorReport),
             0x06 => Some(Self::WarningMessage),
+            0x07 => Some(Self::ProcessTemp),
             0x09 => Some(Self::SetProcessTemp),
+            0x0A => Some(Self::Status),
             0x13 => Some(Self::TempControlMode),
             0x14 => Some(Self::TempControl),
+            0x16 => Some(Self::Circulation),
             0x17 => Some(Self::OperationLock),
+            0x18 => Some(Self::Degassing),
             0x19 => Some(Self::ProcessTempActualSettingMode),
+            0x1B => Some(Self::UpperAlarmLimit),
+            0x1C => Some(Self::LowerAlarmLimit),
+            0x1D => Some(Self::UpperDeltaLimit),
+            0x1E => Some(Self::LowerDeltaLimit),
+            0x26 => Some(Self::PumpSpeed),
+            0x48 => Some(Self::PumpSpeedSetpoint),
+            0x5A => Some(Self::ComGateDigitalInputs),
+            0x5B => Some(Self::ComGateDigitalOutputs),
+            0x5C => Some(Self::ComGateAnalogInput),
+            0x5D => Some(Self::ComGateAnalogOutput),
+            0x5E => Some(Self::EcsStandby),
+            0x6A => Some(Self::DeviceType),
+            0x6B => Some(Self::FirmwareVersion),
+            0x6C => Some(Self::SerialNumberHigh),
+            0x6D => Some(Self::SerialNumberLow),
             _ => None,
         }
     }
@@ -79,12 +276,18 @@ impl From<Address> for u8 {
     }
 }
 
+impl Sender {
+    const fn to_byte(self) -> u8 {
+        match self {
+            Self::Master => b'M',
+            Self::Slave => b'S',
+        }
+    }
+}
+
 impl From<Sender> for u8 {
     fn from(from: Sender) -> Self {
-        match from {
-            Sender::Master => b'M',
-            Sender::Slave => b'S',
-        }
+        from.to_byte()
     }
 }
 
@@ -97,48 +300,264 @@ const fn byte_cmd_msg(sender: u8, addr: [u8; 2], data: [u8; 4]) -> [u8; 10] {
 }
 
 impl Command {
+    /// Master command that queries the current value of `address`.
+    #[must_use]
+    pub const fn query(address: Address) -> Self {
+        Self {
+            sender: Sender::Master,
+            address: address as u8,
+            data: None,
+        }
+    }
+
+    /// Master command that sets `address` to `data`.
     #[must_use]
-    pub fn into_bytes(self) -> [u8; 10] {
-        let addr: [u8; 2] = [
-            to_upper_hex(self.address / 16),
-            to_upper_hex(self.address % 16),
-        ];
-        let mut data = EMPTY_DATA;
-        if let Some(d) = self.data {
-            let hi = (d >> 8) as u8;
-            let lo = (d & 0xFF) as u8;
-            data[0] = to_upper_hex(hi / 16);
-            data[1] = to_upper_hex(hi % 16);
-            data[2] = to_upper_hex(lo / 16);
-            data[3] = to_upper_hex(lo % 16);
+    pub const fn set(address: Address, data: u16) -> Self {
+        Self {
+            sender: Sender::Master,
+            address: address as u8,
+            data: Some(data),
+        }
+    }
+
+    #[must_use]
+    pub fn set_temp_control_mode(mode: TempControlMode) -> Self {
+        Self::set(Address::TempControlMode, mode.into())
+    }
+
+    /// Master command that locks or unlocks the front panel.
+    #[must_use]
+    pub fn set_operation_lock(lock: OperationLock) -> Self {
+        Self::set(Address::OperationLock, lock.into())
+    }
+
+    /// Master command that switches a boolean `address` on or off.
+    #[must_use]
+    pub fn set_switch(address: Address, state: OnOff) -> Self {
+        Self::set(address, state.into())
+    }
+
+    #[must_use]
+    pub fn typed_address(&self) -> CommandAddress {
+        self.address.into()
+    }
+
+    /// Encode the command as a plain frame (including CRLF).
+    ///
+    /// This can be evaluated at compile time, e.g.
+    /// to store fixed frames in flash memory.
+    #[must_use]
+    pub const fn into_bytes(self) -> [u8; 10] {
+        let addr = to_upper_hex_pair(self.address);
+        let data = match self.data {
+            Some(d) => {
+                let [hi, lo] = d.to_be_bytes();
+                let [a, b] = to_upper_hex_pair(hi);
+                let [c, d] = to_upper_hex_pair(lo);
+                [a, b, c, d]
+            }
+            None => EMPTY_DATA,
+        };
+        byte_cmd_msg(self.sender.to_byte(), addr, data)
+    }
+
+    /// Write the complete frame (including CRLF) to `writer`.
+    ///
+    /// This allows to build frames in any [`fmt::Write`] sink
+    /// without an intermediate buffer.
+    pub fn encode_to_fmt<W: fmt::Write>(self, writer: &mut W) -> fmt::Result {
+        self.into_bytes()
+            .into_iter()
+            .try_for_each(|b| writer.write_char(char::from(b)))
+    }
+}
+
+#[cfg(feature = "std")]
+impl Command {
+    /// Write the complete frame (including CRLF) to `writer`.
+    pub fn write_to<W: std::io::Write>(self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&self.into_bytes())
+    }
+
+    /// Read the next frame from `reader`.
+    ///
+    /// Bytes in front of the start character are skipped.
+    pub fn read_from<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut frame = frame::FrameBuf::new(FrameFormat::Plain);
+        let mut byte = [0];
+        loop {
+            reader.read_exact(&mut byte)?;
+            if let Some(res) = frame.push(byte[0]) {
+                return Ok(res?);
+            }
+        }
+    }
+}
+
+/// Formats the wire representation (without CRLF), e.g. `{M0905E8`.
+///
+/// The alternate form (`{:#}`) shows the named address and
+/// the decoded value, e.g. `Master SetProcessTemp: 15.12 °C`.
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !f.alternate() {
+            return self.into_bytes()[..8]
+                .iter()
+                .try_for_each(|b| fmt::Write::write_char(f, char::from(*b)));
+        }
+        write!(f, "{:?} ", self.sender)?;
+        let Some(address) = Address::from_u8(self.address) else {
+            write!(f, "{:#04X}: ", self.address)?;
+            return match self.data {
+                Some(data) => write!(f, "{data:#06X}"),
+                None => write!(f, "?"),
+            };
+        };
+        write!(f, "{address:?}: ")?;
+        let Some(data) = self.data else {
+            return write!(f, "?");
+        };
+        match Response::from_data(address, data) {
+            Ok(
+                Response::SetpointTempControl(x)
+                | Response::InternalTemp(x)
+                | Response::ProcessTemp(x)
+                | Response::SetProcessTemp(x)
+                | Response::UpperAlarmLimit(x)
+                | Response::LowerAlarmLimit(x)
+                | Response::UpperDeltaLimit(x)
+                | Response::LowerDeltaLimit(x),
+            ) => write!(f, "{x}"),
+            Ok(Response::ErrorReport(x)) => write!(f, "{} ({})", x.description(), x.code()),
+            Ok(Response::WarningMessage(x)) => write!(f, "{} ({})", x.description(), x.code()),
+            Ok(Response::TempControlMode(x)) => write!(f, "{x:?}"),
+            Ok(Response::TempControl(x) | Response::Circulation(x) | Response::Degassing(x)) => {
+                write!(f, "{x:?}")
+            }
+            Ok(Response::OperationLock(x)) => write!(f, "{x:?}"),
+            Ok(
+                Response::ProcessTempActualSettingMode(x)
+                | Response::SerialNumberHigh(x)
+                | Response::SerialNumberLow(x),
+            ) => write!(f, "{x}"),
+            Ok(Response::PumpPressure(x)) => write!(f, "{x}"),
+            Ok(Response::CurrentPower(x)) => write!(f, "{x}"),
+            Ok(Response::PumpSpeed(x) | Response::PumpSpeedSetpoint(x)) => write!(f, "{x}"),
+            Ok(Response::ComGateDigitalInputs(x) | Response::ComGateDigitalOutputs(x)) => {
+                write!(f, "{x}")
+            }
+            Ok(Response::ComGateAnalogInput(x) | Response::ComGateAnalogOutput(x)) => {
+                write!(f, "{x}")
+            }
+            Ok(Response::EcsStandby(x)) => write!(f, "{x:?}"),
+            Ok(Response::Status(x)) => write!(f, "{x}"),
+            Ok(Response::DeviceType(x)) => write!(f, "{x:#06X}"),
+            Ok(Response::FirmwareVersion(x)) => write!(f, "{x}"),
+            Err(_) => write!(f, "{data:#06X}"),
         }
-        byte_cmd_msg(self.sender.into(), addr, data)
     }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum ParseError {
     /// Invalid message length
-    MessageLength,
-    /// Non-ASCII str
-    NonAsciiStr,
+    MessageLength { len: usize },
+    /// Non-ASCII character
+    NonAsciiStr { offset: usize },
     /// Invalid sender
-    Sender,
+    Sender { offset: usize, byte: u8 },
     /// Invalid command data
-    CommandData,
+    CommandData { offset: usize, byte: u8 },
     /// Invalid command address
-    Address,
+    Address { offset: usize, byte: u8 },
+    /// Missing start character (`{`)
+    StartByte { byte: u8 },
+    /// Missing line ending (CRLF)
+    LineEnding { offset: usize },
+    /// Invalid checksum
+    Checksum { expected: u8 },
+    /// Invalid device address
+    Device { offset: usize, byte: u8 },
+}
+
+impl ParseError {
+    /// Position of the offending byte within the frame.
+    #[must_use]
+    pub const fn offset(&self) -> Option<usize> {
+        match *self {
+            Self::NonAsciiStr { offset }
+            | Self::Sender { offset, .. }
+            | Self::CommandData { offset, .. }
+            | Self::Address { offset, .. }
+            | Self::LineEnding { offset }
+            | Self::Device { offset, .. } => Some(offset),
+            Self::StartByte { .. } => Some(0),
+            Self::MessageLength { .. } | Self::Checksum { .. } => None,
+        }
+    }
+
+    /// The offending byte.
+    #[must_use]
+    pub const fn byte(&self) -> Option<u8> {
+        match *self {
+            Self::Sender { byte, .. }
+            | Self::CommandData { byte, .. }
+            | Self::Address { byte, .. }
+            | Self::StartByte { byte }
+            | Self::Device { byte, .. } => Some(byte),
+            Self::MessageLength { .. }
+            | Self::NonAsciiStr { .. }
+            | Self::LineEnding { .. }
+            | Self::Checksum { .. } => None,
+        }
+    }
+}
+
+/// Printable form of a byte, e.g. `'X'` or `0x0D`.
+struct DisplayByte(u8);
+
+impl fmt::Display for DisplayByte {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.0.is_ascii_graphic() {
+            write!(f, "'{}'", char::from(self.0))
+        } else {
+            write!(f, "{:#04X}", self.0)
+        }
+    }
 }
 
-#[cfg(feature = "std")]
 impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            Self::MessageLength => write!(f, "Invalid message length"),
-            Self::NonAsciiStr => write!(f, "Non-ASCII str"),
-            Self::Sender => write!(f, "Invalid sender"),
-            Self::CommandData => write!(f, "Invalid command data"),
-            Self::Address => write!(f, "Invalid command address"),
+            Self::MessageLength { len } => write!(f, "Invalid message length: {len}"),
+            Self::NonAsciiStr { offset } => write!(f, "Non-ASCII character at offset {offset}"),
+            Self::Sender { offset, byte } => {
+                write!(f, "Invalid sender {} at offset {offset}", DisplayByte(byte))
+            }
+            Self::CommandData { offset, byte } => write!(
+                f,
+                "Invalid command data {} at offset {offset}",
+                DisplayByte(byte)
+            ),
+            Self::Address { offset, byte } => write!(
+                f,
+                "Invalid command address {} at offset {offset}",
+                DisplayByte(byte)
+            ),
+            Self::StartByte { byte } => {
+                write!(f, "Missing start character, found {}", DisplayByte(byte))
+            }
+            Self::LineEnding { offset } => write!(f, "Missing line ending at offset {offset}"),
+            Self::Checksum { expected } => {
+                write!(f, "Invalid checksum, expected {expected:02X}")
+            }
+            Self::Device { offset, byte } => write!(
+                f,
+                "Invalid device address {} at offset {offset}",
+                DisplayByte(byte)
+            ),
         }
     }
 }
@@ -153,50 +572,195 @@ impl From<ParseError> for Error {
     }
 }
 
+/// Options for [`Command::parse_with`].
+///
+/// The [`Default`] is the strict parser used by [`FromStr`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ParseOptions {
+    /// Accept lowercase hex digits and senders (`m`, `s`)
+    pub ignore_case: bool,
+    /// Accept frames without or with an incomplete CRLF
+    pub optional_line_ending: bool,
+    /// Ignore surrounding whitespace (including the line ending)
+    pub trim_whitespace: bool,
+    /// Don't validate the start character and the line ending
+    pub ignore_delimiters: bool,
+}
+
+impl ParseOptions {
+    #[must_use]
+    pub const fn strict() -> Self {
+        Self {
+            ignore_case: false,
+            optional_line_ending: false,
+            trim_whitespace: false,
+            ignore_delimiters: false,
+        }
+    }
+
+    #[must_use]
+    pub const fn lenient() -> Self {
+        Self {
+            ignore_case: true,
+            optional_line_ending: true,
+            trim_whitespace: true,
+            ignore_delimiters: false,
+        }
+    }
+}
+
+impl Command {
+    pub fn parse_with(s: &str, options: ParseOptions) -> Result<Self, ParseError> {
+        let (frame, line_ending) = if options.optional_line_ending || options.trim_whitespace {
+            let s = if options.trim_whitespace { s.trim() } else { s };
+            check_ascii(s)?;
+            let frame = s
+                .strip_suffix("\r\n")
+                .or_else(|| s.strip_suffix('\n'))
+                .or_else(|| s.strip_suffix('\r'))
+                .unwrap_or(s);
+            if frame.len() != 8 {
+                return Err(ParseError::MessageLength { len: s.len() });
+            }
+            (frame, None)
+        } else {
+            if s.len() != 10 {
+                return Err(ParseError::MessageLength { len: s.len() });
+            }
+            check_ascii(s)?;
+            let (frame, line_ending) = s.split_at(8);
+            (frame, Some(line_ending))
+        };
+
+        let (start, tail) = frame.split_at(1);
+        if !options.ignore_delimiters {
+            check_start(start)?;
+            if let Some(line_ending) = line_ending {
+                check_line_ending(line_ending, frame.len())?;
+            }
+        }
+        parse_body(tail, start.len(), options.ignore_case)
+    }
+
+    /// Parse gateway output or terminal captures.
+    ///
+    /// See [`ParseOptions::lenient`].
+    pub fn from_str_lenient(s: &str) -> Result<Self, ParseError> {
+        Self::parse_with(s, ParseOptions::lenient())
+    }
+}
+
 impl FromStr for Command {
     type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, ParseError> {
-        if s.len() != 10 {
-            return Err(ParseError::MessageLength);
-        }
-        if !s.is_ascii() {
-            return Err(ParseError::NonAsciiStr);
+        Self::parse_with(s, ParseOptions::strict())
+    }
+}
+
+/// Check that `s` only consists of ASCII characters.
+fn check_ascii(s: &str) -> Result<(), ParseError> {
+    match s.bytes().position(|b| !b.is_ascii()) {
+        Some(offset) => Err(ParseError::NonAsciiStr { offset }),
+        None => Ok(()),
+    }
+}
+
+/// Check that the frame begins with the start character.
+fn check_start(start: &str) -> Result<(), ParseError> {
+    match start.as_bytes() {
+        [b'{', ..] => Ok(()),
+        [byte, ..] => Err(ParseError::StartByte { byte: *byte }),
+        [] => Err(ParseError::MessageLength { len: 0 }),
+    }
+}
+
+/// `offset` is the position of the line ending within the frame.
+fn check_line_ending(line_ending: &str, offset: usize) -> Result<(), ParseError> {
+    match line_ending
+        .bytes()
+        .chain(core::iter::repeat(0))
+        .zip(*b"\r\n")
+        .position(|(actual, expected)| actual != expected)
+    {
+        Some(i) => Err(ParseError::LineEnding { offset: offset + i }),
+        None => Ok(()),
+    }
+}
+
+/// Parse sender, address and data of a frame.
+///
+/// `offset` is the position of the body within the frame.
+fn parse_body(body: &str, offset: usize, ignore_case: bool) -> Result<Command, ParseError> {
+    let (sender, tail) = body.split_at(1);
+    let (addr, data) = tail.split_at(2);
+
+    let sender = match sender {
+        "M" => Sender::Master,
+        "S" => Sender::Slave,
+        "m" if ignore_case => Sender::Master,
+        "s" if ignore_case => Sender::Slave,
+        _ => {
+            return Err(ParseError::Sender {
+                offset,
+                byte: sender.as_bytes()[0],
+            });
         }
+    };
 
-        let (_start, tail) = s.split_at(1);
-        let (sender, tail) = tail.split_at(1);
-        let (addr, tail) = tail.split_at(2);
-        let (data, _) = tail.split_at(4);
+    let address = parse_hex(addr, offset + 1, ignore_case)
+        .map_err(|(offset, byte)| ParseError::Address { offset, byte })?;
+    // Two hex digits always fit into a byte.
+    let address = address.to_le_bytes()[0];
 
-        let sender = match sender {
-            "M" => Sender::Master,
-            "S" => Sender::Slave,
-            _ => {
-                return Err(ParseError::Sender);
-            }
-        };
+    let data = match data {
+        "****" => None,
+        _ => Some(
+            parse_hex(data, offset + 3, ignore_case)
+                .map_err(|(offset, byte)| ParseError::CommandData { offset, byte })?,
+        ),
+    };
 
-        let data = match data {
-            "****" => None,
-            _ => Some(u16::from_str_radix(data, 16).map_err(|_| ParseError::CommandData)?),
-        };
+    Ok(Command {
+        sender,
+        address,
+        data,
+    })
+}
 
-        let address = u8::from_str_radix(addr, 16).map_err(|_| ParseError::Address)?;
+/// Parse hex digits at position `offset` of a frame.
+///
+/// Returns the position and the value of the first invalid byte on error.
+fn parse_hex(s: &str, offset: usize, ignore_case: bool) -> Result<u16, (usize, u8)> {
+    s.bytes().enumerate().try_fold(0_u16, |acc, (i, b)| {
+        let digit = hex_digit(b, ignore_case).ok_or((offset + i, b))?;
+        Ok(acc << 4 | u16::from(digit))
+    })
+}
 
-        Ok(Command {
-            sender,
-            address,
-            data,
-        })
+/// Value of a single hex digit.
+const fn hex_digit(b: u8, ignore_case: bool) -> Option<u8> {
+    match b {
+        b'0'..=b'9' => Some(b - b'0'),
+        b'A'..=b'F' => Some(b - b'A' + 10),
+        b'a'..=b'f' if ignore_case => Some(b - b'a' + 10),
+        _ => None,
     }
 }
 
 // TODO: replace this conversation with other methods
 // as soon as there is something available within core.
-fn to_upper_hex(x: u8) -> u8 {
-    match x {
-        0..=9 => b'0' + x,
-        10..=15 => b'A' + (x - 10),
-        _ => unreachable!(),
-    }
+const HEX_DIGITS: [u8; 16] = *b"0123456789ABCDEF";
+
+/// Upper case hex digit of the lower nibble of `x`.
+///
+/// Masking keeps the index within the table so this can never panic,
+/// regardless of the value passed in.
+const fn to_upper_hex(x: u8) -> u8 {
+    HEX_DIGITS[(x & 0x0F) as usize]
+}
+
+/// The two upper case hex digits of `x`.
+const fn to_upper_hex_pair(x: u8) -> [u8; 2] {
+    [to_upper_hex(x >> 4), to_upper_hex(x)]
 }
@@ -0,0 +1,31 @@
+use std::io::{Cursor, Read, Result, Write};
+
+/// Transport that replays a fixed input and records the output.
+pub(crate) struct Mock {
+    rx: Cursor<Vec<u8>>,
+    pub(crate) tx: Vec<u8>,
+}
+
+impl Mock {
+    pub(crate) fn new(rx: &[u8]) -> Self {
+        Self {
+            rx: Cursor::new(rx.to_vec()),
+            tx: vec![],
+        }
+    }
+}
+
+impl Read for Mock {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        self.rx.read(buf)
+    }
+}
+
+impl Write for Mock {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.tx.write(buf)
+    }
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
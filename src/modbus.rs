@@ -0,0 +1,174 @@
+//! Mapping of the parameters to Modbus registers.
+//!
+//! Each PB address is mapped to the holding register with
+//! the same number and the same 16-bit data word.
+
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Address, DecodeError, Power, Response, Temperature};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Error {
+    /// Unknown holding register
+    Register(u16),
+    /// Invalid register value
+    Decode(DecodeError),
+}
+
+impl From<DecodeError> for Error {
+    fn from(from: DecodeError) -> Self {
+        Self::Decode(from)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::Register(x) => write!(f, "Unknown holding register: {x:#06X}"),
+            Self::Decode(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+#[cfg(feature = "std")]
+impl From<Error> for io::Error {
+    fn from(e: Error) -> io::Error {
+        io::Error::new(io::ErrorKind::InvalidData, e)
+    }
+}
+
+/// Scaling of a register value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Scaling {
+    /// Signed temperature in 0.01 °C
+    CentiCelsius,
+    /// Signed value in 0.01 %
+    CentiPercent,
+    /// Signed value without scaling
+    Signed,
+    /// Code, enumeration or unsigned value without scaling
+    Raw,
+}
+
+impl Scaling {
+    /// Physical value of a register value.
+    #[must_use]
+    pub fn to_f64(self, value: u16) -> f64 {
+        match self {
+            Self::CentiCelsius => Temperature::from_data(value).celsius(),
+            Self::CentiPercent => Power::from_data(value).percent(),
+            Self::Signed => f64::from(i16::from_be_bytes(value.to_be_bytes())),
+            Self::Raw => f64::from(value),
+        }
+    }
+
+    /// Register value of a physical value.
+    ///
+    /// Returns `None` if the value can't be represented
+    /// (e.g. fractions of raw values).
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn from_f64(self, value: f64) -> Option<u16> {
+        match self {
+            Self::CentiCelsius => Temperature::from_celsius(value).map(Temperature::to_data),
+            Self::CentiPercent => Power::from_percent(value).map(Power::to_data),
+            Self::Signed => {
+                let x = value as i16;
+                // Only integers are valid.
+                #[allow(clippy::float_cmp)]
+                (f64::from(x) == value).then_some(u16::from_be_bytes(x.to_be_bytes()))
+            }
+            Self::Raw => {
+                let x = value as u16;
+                // Only integers are valid.
+                #[allow(clippy::float_cmp)]
+                (f64::from(x) == value).then_some(x)
+            }
+        }
+    }
+}
+
+/// Modbus holding register of a parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Register {
+    pub number: u16,
+    pub scaling: Scaling,
+    pub writable: bool,
+}
+
+/// Holding register of `address`.
+#[must_use]
+pub const fn register(address: Address) -> Register {
+    let scaling = match address {
+        Address::SetpointTempControl
+        | Address::InternalTemp
+        | Address::ProcessTemp
+        | Address::SetProcessTemp
+        | Address::UpperAlarmLimit
+        | Address::LowerAlarmLimit
+        | Address::UpperDeltaLimit
+        | Address::LowerDeltaLimit => Scaling::CentiCelsius,
+        Address::CurrentPower => Scaling::CentiPercent,
+        Address::PumpPressure => Scaling::Signed,
+        Address::ErrorReport
+        | Address::WarningMessage
+        | Address::Status
+        | Address::TempControlMode
+        | Address::TempControl
+        | Address::Circulation
+        | Address::OperationLock
+        | Address::Degassing
+        | Address::ProcessTempActualSettingMode
+        | Address::PumpSpeed
+        | Address::PumpSpeedSetpoint
+        | Address::ComGateDigitalInputs
+        | Address::ComGateDigitalOutputs
+        | Address::ComGateAnalogInput
+        | Address::ComGateAnalogOutput
+        | Address::EcsStandby
+        | Address::DeviceType
+        | Address::FirmwareVersion
+        | Address::SerialNumberHigh
+        | Address::SerialNumberLow => Scaling::Raw,
+    };
+    Register {
+        number: address as u16,
+        scaling,
+        writable: address.is_writable(),
+    }
+}
+
+/// Address of the holding register `number`.
+#[must_use]
+pub fn address(number: u16) -> Option<Address> {
+    u8::try_from(number).ok().and_then(Address::from_u8)
+}
+
+/// Decode the value of the holding register `number`.
+pub fn decode(number: u16, value: u16) -> Result<Response, Error> {
+    let address = address(number).ok_or(Error::Register(number))?;
+    Ok(Response::from_data(address, value)?)
+}
+
+/// Holding register number and value of a response.
+#[must_use]
+pub fn encode(response: &Response) -> (u16, u16) {
+    (register(response.address()).number, response.data())
+}
@@ -0,0 +1,54 @@
+use super::*;
+use crate::OnOff;
+
+#[test]
+fn register_numbers() {
+    let reg = register(Address::SetProcessTemp);
+    assert_eq!(reg.number, 0x09);
+    assert_eq!(reg.scaling, Scaling::CentiCelsius);
+    assert!(reg.writable);
+    let reg = register(Address::ErrorReport);
+    assert_eq!(reg.number, 0x05);
+    assert_eq!(reg.scaling, Scaling::Raw);
+    assert!(!reg.writable);
+}
+
+#[test]
+fn register_to_address() {
+    assert_eq!(address(0x16), Some(Address::Circulation));
+    assert_eq!(address(0x7F), None);
+    assert_eq!(address(0x0101), None);
+}
+
+#[test]
+fn scaling() {
+    assert!((Scaling::CentiCelsius.to_f64(0xFF38) + 2.0).abs() < f64::EPSILON);
+    assert_eq!(Scaling::CentiCelsius.from_f64(36.5), Some(3650));
+    assert_eq!(Scaling::CentiCelsius.from_f64(400.0), None);
+    assert!((Scaling::Raw.to_f64(3) - 3.0).abs() < f64::EPSILON);
+    assert_eq!(Scaling::Raw.from_f64(1.0), Some(1));
+    assert_eq!(Scaling::Raw.from_f64(-1.0), None);
+    assert_eq!(Scaling::Raw.from_f64(1.5), None);
+}
+
+#[test]
+fn decode_and_encode() {
+    let response = decode(0x14, 1).unwrap();
+    assert_eq!(response, Response::TempControl(OnOff::On));
+    assert_eq!(encode(&response), (0x14, 1));
+    assert_eq!(
+        decode(0x14, 2).unwrap_err(),
+        Error::Decode(DecodeError::InvalidValue(crate::InvalidValue(2)))
+    );
+    assert_eq!(decode(0x7F, 0), Err(Error::Register(0x7F)));
+    assert_eq!(decode(0x0101, 0), Err(Error::Register(0x0101)));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn error_display() {
+    assert_eq!(
+        Error::Register(0x0101).to_string(),
+        "Unknown holding register: 0x0101"
+    );
+}
@@ -0,0 +1,229 @@
+//! Packaged data exchange.
+//!
+//! A package transfers multiple values in a single frame.
+//! It starts like a normal frame with the start character and the
+//! sender, followed by `*`, the number of entries (two hex digits)
+//! and the entries. Each entry consists of the address (two hex digits)
+//! and the data (four hex digits or `****` for queries), e.g.
+//!
+//! ```text
+//! {M*0201****07****\r\n
+//! ```
+//!
+//! queries the internal and the process temperature at once.
+
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io::{Error, ErrorKind};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    check_ascii, check_line_ending, check_start, parse_hex, to_upper_hex_pair, BufferOverflow,
+    Command, ParseError, Sender,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Maximum number of entries of a package.
+pub const MAX_ENTRIES: usize = 16;
+
+/// Length of a package frame with [`MAX_ENTRIES`] entries.
+pub const MAX_FRAME_LEN: usize = frame_len(MAX_ENTRIES);
+
+const HEADER_LEN: usize = 5;
+const ENTRY_LEN: usize = 6;
+
+const fn frame_len(entries: usize) -> usize {
+    HEADER_LEN + entries * ENTRY_LEN + 2
+}
+
+/// The package already contains [`MAX_ENTRIES`] entries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PackageFull;
+
+impl fmt::Display for PackageFull {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Package is full ({MAX_ENTRIES} entries)")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PackageFull {}
+
+#[cfg(feature = "std")]
+impl From<PackageFull> for Error {
+    fn from(e: PackageFull) -> Error {
+        Error::new(ErrorKind::InvalidInput, e)
+    }
+}
+
+/// Single value of a [`Package`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Entry {
+    pub address: u8,
+    pub data: Option<u16>,
+}
+
+impl From<Command> for Entry {
+    fn from(from: Command) -> Self {
+        Self {
+            address: from.address,
+            data: from.data,
+        }
+    }
+}
+
+/// Frame with multiple values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Package {
+    sender: Sender,
+    entries: [Entry; MAX_ENTRIES],
+    len: usize,
+}
+
+impl Package {
+    #[must_use]
+    pub const fn new(sender: Sender) -> Self {
+        Self {
+            sender,
+            entries: [Entry {
+                address: 0,
+                data: None,
+            }; MAX_ENTRIES],
+            len: 0,
+        }
+    }
+
+    /// Package that queries all `addresses` at once.
+    pub fn query<I, A>(addresses: I) -> Result<Self, PackageFull>
+    where
+        I: IntoIterator<Item = A>,
+        A: Into<u8>,
+    {
+        let mut package = Self::new(Sender::Master);
+        for address in addresses {
+            package.push(Entry {
+                address: address.into(),
+                data: None,
+            })?;
+        }
+        Ok(package)
+    }
+
+    #[must_use]
+    pub const fn sender(&self) -> Sender {
+        self.sender
+    }
+
+    #[must_use]
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries[..self.len]
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn push(&mut self, entry: Entry) -> Result<(), PackageFull> {
+        let slot = self.entries.get_mut(self.len).ok_or(PackageFull)?;
+        *slot = entry;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// The entries as single commands.
+    pub fn commands(&self) -> impl Iterator<Item = Command> + '_ {
+        self.entries().iter().map(|entry| Command {
+            sender: self.sender,
+            address: entry.address,
+            data: entry.data,
+        })
+    }
+
+    /// Length of the encoded frame (including CRLF).
+    #[must_use]
+    pub const fn frame_len(&self) -> usize {
+        frame_len(self.len)
+    }
+
+    /// Encode the frame into `buf` and return the number of bytes written.
+    pub fn encode(&self, buf: &mut [u8]) -> Result<usize, BufferOverflow> {
+        let len = self.frame_len();
+        let Some(buf) = buf.get_mut(..len) else {
+            return Err(BufferOverflow { written: 0 });
+        };
+        #[allow(clippy::cast_possible_truncation)] // at most MAX_ENTRIES
+        let count = self.len as u8;
+        let [c0, c1] = to_upper_hex_pair(count);
+        buf[..HEADER_LEN].copy_from_slice(&[b'{', self.sender.into(), b'*', c0, c1]);
+        for (cmd, dst) in self
+            .commands()
+            .zip(buf[HEADER_LEN..].chunks_exact_mut(ENTRY_LEN))
+        {
+            dst.copy_from_slice(&cmd.into_bytes()[2..8]);
+        }
+        buf[len - 2..].copy_from_slice(b"\r\n");
+        Ok(len)
+    }
+
+    /// Parse a complete package frame (including CRLF).
+    pub fn parse(s: &str) -> Result<Self, ParseError> {
+        let len = s.len();
+        if len < frame_len(0) || len > MAX_FRAME_LEN {
+            return Err(ParseError::MessageLength { len });
+        }
+        check_ascii(s)?;
+        let (header, tail) = s.split_at(HEADER_LEN);
+        let (entries, line_ending) = tail.split_at(tail.len() - 2);
+        check_start(header)?;
+        check_line_ending(line_ending, len - 2)?;
+        let sender = match (header.as_bytes()[1], header.as_bytes()[2]) {
+            (b'M', b'*') => Sender::Master,
+            (b'S', b'*') => Sender::Slave,
+            (b'M' | b'S', byte) => return Err(ParseError::Sender { offset: 2, byte }),
+            (byte, _) => return Err(ParseError::Sender { offset: 1, byte }),
+        };
+        let count =
+            parse_hex(&header[3..], 3, false).map_err(|_| ParseError::MessageLength { len })?;
+        if usize::from(count) * ENTRY_LEN != entries.len() {
+            return Err(ParseError::MessageLength { len });
+        }
+        let mut package = Self::new(sender);
+        for (i, entry) in entries.as_bytes().chunks_exact(ENTRY_LEN).enumerate() {
+            let offset = HEADER_LEN + i * ENTRY_LEN;
+            // The frame has been checked to be ASCII.
+            let entry = core::str::from_utf8(entry).map_err(|e| ParseError::NonAsciiStr {
+                offset: offset + e.valid_up_to(),
+            })?;
+            let (address, data) = entry.split_at(2);
+            let address = parse_hex(address, offset, false)
+                .map_err(|(offset, byte)| ParseError::Address { offset, byte })?;
+            // Two hex digits always fit into a byte.
+            let address = address.to_le_bytes()[0];
+            let data = match data {
+                "****" => None,
+                _ => Some(
+                    parse_hex(data, offset + 2, false)
+                        .map_err(|(offset, byte)| ParseError::CommandData { offset, byte })?,
+                ),
+            };
+            package
+                .push(Entry { address, data })
+                .map_err(|_| ParseError::MessageLength { len })?;
+        }
+        Ok(package)
+    }
+}
@@ -0,0 +1,86 @@
+use super::*;
+use crate::Address;
+
+#[test]
+fn encode_query() {
+    let package = Package::query([Address::InternalTemp, Address::ProcessTemp]).unwrap();
+    let mut buf = [0; MAX_FRAME_LEN];
+    let len = package.encode(&mut buf).unwrap();
+    assert_eq!(&buf[..len], b"{M*0201****07****\r\n");
+}
+
+#[test]
+fn encode_into_small_buffer() {
+    let package = Package::query([Address::InternalTemp]).unwrap();
+    let mut buf = [0; 12];
+    assert_eq!(package.encode(&mut buf), Err(BufferOverflow { written: 0 }));
+}
+
+#[test]
+fn parse_reply() {
+    let package = Package::parse("{S*020109C40707D0\r\n").unwrap();
+    assert_eq!(package.sender(), Sender::Slave);
+    assert_eq!(package.len(), 2);
+    let mut commands = package.commands();
+    assert_eq!(commands.next().unwrap().data, Some(0x09C4));
+    let cmd = commands.next().unwrap();
+    assert_eq!(cmd.address, 0x07);
+    assert_eq!(cmd.data, Some(0x07D0));
+    assert!(commands.next().is_none());
+}
+
+#[test]
+fn parse_invalid_package() {
+    assert_eq!(
+        Package::parse("{S*030109C4\r\n"),
+        Err(ParseError::MessageLength { len: 13 })
+    );
+    assert_eq!(
+        Package::parse("{S0109C4\r\n"),
+        Err(ParseError::Sender {
+            offset: 2,
+            byte: b'0'
+        })
+    );
+    assert_eq!(
+        Package::parse("{S*010109X4\r\n"),
+        Err(ParseError::CommandData {
+            offset: 9,
+            byte: b'X'
+        })
+    );
+    assert_eq!(
+        Package::parse("{S*010109C4\n\n"),
+        Err(ParseError::LineEnding { offset: 11 })
+    );
+    assert!(Package::parse("{S*00\r\n").unwrap().is_empty());
+}
+
+#[test]
+fn package_full() {
+    let mut package = Package::new(Sender::Master);
+    let entry = Entry::from(Command::query(Address::InternalTemp));
+    for _ in 0..MAX_ENTRIES {
+        package.push(entry).unwrap();
+    }
+    assert_eq!(package.push(entry), Err(PackageFull));
+}
+
+#[test]
+fn round_trip() {
+    let mut package = Package::new(Sender::Slave);
+    for address in 0..MAX_ENTRIES {
+        let address = u8::try_from(address).unwrap();
+        package
+            .push(Entry {
+                address,
+                data: Some(u16::from(address) << 8),
+            })
+            .unwrap();
+    }
+    let mut buf = [0; MAX_FRAME_LEN];
+    let len = package.encode(&mut buf).unwrap();
+    assert_eq!(len, MAX_FRAME_LEN);
+    let frame = core::str::from_utf8(&buf).unwrap();
+    assert_eq!(Package::parse(frame), Ok(package));
+}
@@ -0,0 +1,131 @@
+//! Cyclic acquisition of values.
+
+use std::{
+    io::{Read, Result, Write},
+    ops::ControlFlow,
+    sync::mpsc::Sender,
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+use crate::{Address, PilotOne, Response};
+
+#[cfg(test)]
+mod tests;
+
+/// Decoded value with the time it was received.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reading {
+    pub timestamp: SystemTime,
+    pub response: Response,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    address: Address,
+    interval: Duration,
+    due: Instant,
+}
+
+/// Queries a set of addresses in individual intervals.
+///
+/// The queries are paced by the minimum interval of the
+/// client (see [`PilotOne::with_min_interval`]).
+#[derive(Debug, Clone)]
+pub struct Poller {
+    entries: Vec<Entry>,
+}
+
+impl Default for Poller {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Poller {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Query `address` every `interval`, starting immediately.
+    ///
+    /// Addresses that are due at the same time are queried
+    /// in the order they have been added.
+    pub fn add(&mut self, address: Address, interval: Duration) -> &mut Self {
+        self.entries.push(Entry {
+            address,
+            interval,
+            due: Instant::now(),
+        });
+        self
+    }
+
+    /// Point in time at which the next query is due.
+    #[must_use]
+    pub fn next_due(&self) -> Option<Instant> {
+        self.entries.iter().map(|e| e.due).min()
+    }
+
+    /// Query all addresses that are due and pass the readings to `on_reading`.
+    ///
+    /// Returns the number of queries that have been sent.
+    pub fn poll<T, F>(&mut self, client: &mut PilotOne<T>, mut on_reading: F) -> usize
+    where
+        T: Read + Write,
+        F: FnMut(Result<Reading>) -> ControlFlow<()>,
+    {
+        let mut count = 0;
+        for i in 0..self.entries.len() {
+            let now = Instant::now();
+            let entry = &mut self.entries[i];
+            if entry.due > now {
+                continue;
+            }
+            entry.due = now + entry.interval;
+            let address = entry.address;
+            let reading = client.read(address).map(|response| Reading {
+                timestamp: SystemTime::now(),
+                response,
+            });
+            count += 1;
+            if on_reading(reading).is_break() {
+                break;
+            }
+        }
+        count
+    }
+
+    /// Poll until `on_reading` breaks.
+    pub fn run<T, F>(&mut self, client: &mut PilotOne<T>, mut on_reading: F)
+    where
+        T: Read + Write,
+        F: FnMut(Result<Reading>) -> ControlFlow<()>,
+    {
+        let mut stop = false;
+        while !stop {
+            let Some(due) = self.next_due() else {
+                return;
+            };
+            thread::sleep(due.saturating_duration_since(Instant::now()));
+            self.poll(client, |reading| {
+                let res = on_reading(reading);
+                stop = res.is_break();
+                res
+            });
+        }
+    }
+
+    /// Poll and send the readings to a channel until the receiver is dropped.
+    pub fn run_with_sender<T>(&mut self, client: &mut PilotOne<T>, tx: &Sender<Result<Reading>>)
+    where
+        T: Read + Write,
+    {
+        self.run(client, |reading| match tx.send(reading) {
+            Ok(()) => ControlFlow::Continue(()),
+            Err(_) => ControlFlow::Break(()),
+        });
+    }
+}
@@ -0,0 +1,68 @@
+use std::sync::mpsc::channel;
+
+use super::*;
+use crate::{mock::Mock, ErrorCode, Temperature};
+
+#[test]
+fn poll_due_addresses_in_order() {
+    let mut client =
+        PilotOne::new(Mock::new(b"{S0109C4\r\n{S050000\r\n")).with_min_interval(Duration::ZERO);
+    let mut poller = Poller::new();
+    poller
+        .add(Address::InternalTemp, Duration::from_secs(60))
+        .add(Address::ErrorReport, Duration::from_secs(60));
+    let mut readings = vec![];
+    let count = poller.poll(&mut client, |r| {
+        readings.push(r.unwrap().response);
+        ControlFlow::Continue(())
+    });
+    assert_eq!(count, 2);
+    assert_eq!(
+        readings,
+        [
+            Response::InternalTemp(Temperature::from_centi_celsius(2500)),
+            Response::ErrorReport(ErrorCode::NoError),
+        ]
+    );
+    assert_eq!(client.get_ref().tx, b"{M01****\r\n{M05****\r\n");
+
+    // Nothing is due anymore
+    assert_eq!(poller.poll(&mut client, |_| ControlFlow::Continue(())), 0);
+}
+
+#[test]
+fn respect_min_interval_of_client() {
+    let spacing = Duration::from_millis(20);
+    let mut client =
+        PilotOne::new(Mock::new(b"{S0109C4\r\n{S0109C4\r\n")).with_min_interval(spacing);
+    let mut poller = Poller::new();
+    poller.add(Address::InternalTemp, Duration::ZERO);
+    let mut timestamps = vec![];
+    poller.run(&mut client, |r| {
+        timestamps.push(r.unwrap().timestamp);
+        if timestamps.len() == 2 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    });
+    let elapsed = timestamps[1].duration_since(timestamps[0]).unwrap();
+    assert!(elapsed >= spacing);
+}
+
+#[test]
+fn run_with_sender_until_error() {
+    let mut client = PilotOne::new(Mock::new(b"{S0109C4\r\n")).with_min_interval(Duration::ZERO);
+    let mut poller = Poller::new();
+    poller.add(Address::InternalTemp, Duration::ZERO);
+    let (tx, rx) = channel();
+    let handle = thread::spawn(move || {
+        let first = rx.recv().unwrap();
+        let second = rx.recv().unwrap();
+        (first, second)
+    });
+    poller.run_with_sender(&mut client, &tx);
+    let (first, second) = handle.join().unwrap();
+    assert!(first.is_ok());
+    assert!(second.is_err());
+}
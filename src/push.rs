@@ -0,0 +1,210 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{hex_digit, to_upper_hex_pair, Command, FrameFormat, ParseError, Sender};
+
+#[cfg(test)]
+mod tests;
+
+/// Progress of a [`PushParser`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ParseEvent {
+    /// A start character has been received
+    FrameStart,
+    /// A frame has been received completely
+    FrameComplete(Command),
+    /// The frame has been dropped
+    FrameError(ParseError),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Idle,
+    Sender,
+    Address { digits: u8 },
+    Data { digits: u8 },
+    Checksum { digits: u8 },
+    Cr,
+    Lf,
+}
+
+/// Parser that is fed one byte at a time.
+///
+/// Instead of buffering the frame the fields are decoded
+/// as they arrive, so the parser only consists of a few
+/// bytes of state. This makes it suitable to be driven
+/// directly from a UART RX interrupt handler.
+///
+/// Bytes in front of the start character are skipped.
+/// A start character in the middle of a frame aborts
+/// the current frame and starts a new one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PushParser {
+    format: FrameFormat,
+    state: State,
+    offset: usize,
+    sum: u8,
+    sender: Sender,
+    address: u8,
+    data: u16,
+    has_data: bool,
+}
+
+impl Default for PushParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PushParser {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::with_frame_format(FrameFormat::Plain)
+    }
+
+    #[must_use]
+    pub const fn with_frame_format(format: FrameFormat) -> Self {
+        Self {
+            format,
+            state: State::Idle,
+            offset: 0,
+            sum: 0,
+            sender: Sender::Master,
+            address: 0,
+            data: 0,
+            has_data: false,
+        }
+    }
+
+    #[must_use]
+    pub const fn frame_format(&self) -> FrameFormat {
+        self.format
+    }
+
+    /// Returns `true` while a frame is received.
+    #[must_use]
+    pub const fn in_frame(&self) -> bool {
+        !matches!(self.state, State::Idle)
+    }
+
+    /// Drop the frame that is currently received.
+    pub fn reset(&mut self) {
+        self.state = State::Idle;
+    }
+
+    /// Process the next byte.
+    pub fn push(&mut self, byte: u8) -> Option<ParseEvent> {
+        if self.state == State::Idle {
+            if byte != b'{' {
+                return None;
+            }
+            self.start();
+            return Some(ParseEvent::FrameStart);
+        }
+        match self.step(byte) {
+            Ok(None) => {
+                self.offset += 1;
+                None
+            }
+            Ok(Some(cmd)) => {
+                self.state = State::Idle;
+                Some(ParseEvent::FrameComplete(cmd))
+            }
+            Err(err) => {
+                if byte == b'{' {
+                    self.start();
+                } else {
+                    self.state = State::Idle;
+                }
+                Some(ParseEvent::FrameError(err))
+            }
+        }
+    }
+
+    fn start(&mut self) {
+        self.state = State::Sender;
+        self.offset = 1;
+        self.sum = b'{';
+        self.address = 0;
+        self.data = 0;
+        self.has_data = false;
+    }
+
+    const fn command(&self) -> Command {
+        Command {
+            sender: self.sender,
+            address: self.address,
+            data: if self.has_data { Some(self.data) } else { None },
+        }
+    }
+
+    fn step(&mut self, byte: u8) -> Result<Option<Command>, ParseError> {
+        let offset = self.offset;
+        match self.state {
+            State::Idle => return Ok(None),
+            State::Sender => {
+                self.sender = match byte {
+                    b'M' => Sender::Master,
+                    b'S' => Sender::Slave,
+                    _ => return Err(ParseError::Sender { offset, byte }),
+                };
+                self.state = State::Address { digits: 0 };
+            }
+            State::Address { digits } => {
+                let digit = hex_digit(byte, false).ok_or(ParseError::Address { offset, byte })?;
+                self.address = self.address << 4 | digit;
+                self.state = if digits == 0 {
+                    State::Address { digits: 1 }
+                } else {
+                    State::Data { digits: 0 }
+                };
+            }
+            State::Data { digits } => {
+                if digits == 0 {
+                    self.has_data = byte != b'*';
+                }
+                if self.has_data {
+                    let digit =
+                        hex_digit(byte, false).ok_or(ParseError::CommandData { offset, byte })?;
+                    self.data = self.data << 4 | u16::from(digit);
+                } else if byte != b'*' {
+                    return Err(ParseError::CommandData { offset, byte });
+                }
+                self.state = match (digits, self.format) {
+                    (0..=2, _) => State::Data { digits: digits + 1 },
+                    (_, FrameFormat::Plain) => State::Cr,
+                    (_, FrameFormat::Checksum) => State::Checksum { digits: 0 },
+                };
+            }
+            State::Checksum { digits } => {
+                let [hi, lo] = to_upper_hex_pair(self.sum);
+                if byte != if digits == 0 { hi } else { lo } {
+                    return Err(ParseError::Checksum { expected: self.sum });
+                }
+                self.state = if digits == 0 {
+                    State::Checksum { digits: 1 }
+                } else {
+                    State::Cr
+                };
+                return Ok(None);
+            }
+            State::Cr => {
+                if byte != b'\r' {
+                    return Err(ParseError::LineEnding { offset });
+                }
+                self.state = State::Lf;
+                return Ok(None);
+            }
+            State::Lf => {
+                if byte != b'\n' {
+                    return Err(ParseError::LineEnding { offset });
+                }
+                return Ok(Some(self.command()));
+            }
+        }
+        self.sum = self.sum.wrapping_add(byte);
+        Ok(None)
+    }
+}
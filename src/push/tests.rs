@@ -0,0 +1,115 @@
+use super::*;
+use crate::{Address, CommandIter};
+
+fn feed(parser: &mut PushParser, bytes: &[u8]) -> Option<ParseEvent> {
+    bytes.iter().fold(None, |_, b| parser.push(*b))
+}
+
+#[test]
+fn parse_frames() {
+    let mut parser = PushParser::new();
+    assert_eq!(parser.push(b'\n'), None);
+    assert_eq!(parser.push(b'{'), Some(ParseEvent::FrameStart));
+    assert!(parser.in_frame());
+    assert_eq!(
+        feed(&mut parser, b"M01****\r\n"),
+        Some(ParseEvent::FrameComplete(Command::query(
+            Address::InternalTemp
+        )))
+    );
+    assert!(!parser.in_frame());
+    assert_eq!(
+        feed(&mut parser, b"{S0109C4\r\n"),
+        Some(ParseEvent::FrameComplete(Command {
+            sender: Sender::Slave,
+            address: 0x01,
+            data: Some(0x09C4)
+        }))
+    );
+}
+
+#[test]
+fn report_errors() {
+    let mut parser = PushParser::new();
+    assert_eq!(
+        feed(&mut parser, b"{M0X"),
+        Some(ParseEvent::FrameError(ParseError::Address {
+            offset: 3,
+            byte: b'X'
+        }))
+    );
+    assert!(!parser.in_frame());
+    assert_eq!(
+        feed(&mut parser, b"{M01**1"),
+        Some(ParseEvent::FrameError(ParseError::CommandData {
+            offset: 6,
+            byte: b'1'
+        }))
+    );
+    assert_eq!(
+        feed(&mut parser, b"{M01****\n"),
+        Some(ParseEvent::FrameError(ParseError::LineEnding { offset: 8 }))
+    );
+}
+
+#[test]
+fn restart_on_start_character() {
+    let mut parser = PushParser::new();
+    assert_eq!(
+        feed(&mut parser, b"{M0{"),
+        Some(ParseEvent::FrameError(ParseError::Address {
+            offset: 3,
+            byte: b'{'
+        }))
+    );
+    assert!(parser.in_frame());
+    assert_eq!(
+        feed(&mut parser, b"M01****\r\n"),
+        Some(ParseEvent::FrameComplete(Command::query(
+            Address::InternalTemp
+        )))
+    );
+    assert_eq!(feed(&mut parser, b"{M0"), None);
+    parser.reset();
+    assert!(!parser.in_frame());
+}
+
+#[test]
+fn parse_frames_with_checksum() {
+    let mut parser = PushParser::with_frame_format(FrameFormat::Checksum);
+    assert_eq!(
+        feed(&mut parser, b"{M0905E813\r\n"),
+        Some(ParseEvent::FrameComplete(Command::set(
+            Address::SetProcessTemp,
+            0x05E8
+        )))
+    );
+    assert_eq!(
+        feed(&mut parser, b"{M0905E814"),
+        Some(ParseEvent::FrameError(ParseError::Checksum {
+            expected: 0x13
+        }))
+    );
+}
+
+#[test]
+fn same_results_as_iterator() {
+    let bytes = b"xx{M01****\r\n{S0109C4\r\n{X01****\r\n{M0905e8\r\n{S0A0001\r\n\
+        {S0109{S0109C4\r\n{M01****\r{M01****\r\n{M01*****\r\n{S0109C4\r\n";
+    let mut parser = PushParser::new();
+    let mut iter = CommandIter::new(bytes);
+    let mut complete = 0;
+    for b in bytes {
+        match parser.push(*b) {
+            Some(ParseEvent::FrameComplete(cmd)) => {
+                complete += 1;
+                assert_eq!(iter.next(), Some(Ok(cmd)));
+            }
+            Some(ParseEvent::FrameError(_)) => assert!(iter.next().unwrap().is_err()),
+            Some(ParseEvent::FrameStart) | None => {}
+        }
+    }
+    assert_eq!(iter.next(), None);
+    // The valid frames after truncated ones are not lost.
+    assert_eq!(complete, 6);
+}
@@ -0,0 +1,166 @@
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::centi;
+
+/// Pump speed in revolutions per minute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PumpSpeed(u16);
+
+impl PumpSpeed {
+    #[must_use]
+    pub const fn from_rpm(x: u16) -> Self {
+        Self(x)
+    }
+
+    #[must_use]
+    pub const fn rpm(self) -> u16 {
+        self.0
+    }
+
+    #[must_use]
+    pub const fn from_data(x: u16) -> Self {
+        Self(x)
+    }
+
+    #[must_use]
+    pub const fn to_data(self) -> u16 {
+        self.0
+    }
+}
+
+impl From<u16> for PumpSpeed {
+    fn from(from: u16) -> Self {
+        Self::from_data(from)
+    }
+}
+
+impl From<PumpSpeed> for u16 {
+    fn from(from: PumpSpeed) -> Self {
+        from.to_data()
+    }
+}
+
+/// Formats the speed in rpm, e.g. `1500 rpm`.
+impl fmt::Display for PumpSpeed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} rpm", self.0)
+    }
+}
+
+/// Relative pressure in mbar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Pressure(i16);
+
+impl Pressure {
+    #[must_use]
+    pub const fn from_millibar(x: i16) -> Self {
+        Self(x)
+    }
+
+    #[must_use]
+    pub const fn millibar(self) -> i16 {
+        self.0
+    }
+
+    #[must_use]
+    pub fn bar(self) -> f64 {
+        f64::from(self.0) / 1000.0
+    }
+
+    #[must_use]
+    pub const fn from_data(x: u16) -> Self {
+        Self(i16::from_be_bytes(x.to_be_bytes()))
+    }
+
+    #[must_use]
+    pub const fn to_data(self) -> u16 {
+        u16::from_be_bytes(self.0.to_be_bytes())
+    }
+}
+
+impl From<u16> for Pressure {
+    fn from(from: u16) -> Self {
+        Self::from_data(from)
+    }
+}
+
+impl From<Pressure> for u16 {
+    fn from(from: Pressure) -> Self {
+        from.to_data()
+    }
+}
+
+/// Formats the pressure in mbar, e.g. `250 mbar`.
+impl fmt::Display for Pressure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} mbar", self.0)
+    }
+}
+
+/// Power in percent of the maximum power.
+///
+/// The device encodes the power as signed 16-bit value
+/// with a resolution of 0.01 %. Negative values mean cooling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Power(i16);
+
+impl Power {
+    #[must_use]
+    pub const fn from_centi_percent(x: i16) -> Self {
+        Self(x)
+    }
+
+    #[must_use]
+    pub const fn centi_percent(self) -> i16 {
+        self.0
+    }
+
+    /// Returns `None` if the value can't be represented.
+    #[must_use]
+    pub fn from_percent(x: f64) -> Option<Self> {
+        centi::from_f64(x).map(Self)
+    }
+
+    #[must_use]
+    pub fn percent(self) -> f64 {
+        centi::to_f64(self.0)
+    }
+
+    #[must_use]
+    pub const fn from_data(x: u16) -> Self {
+        Self(i16::from_be_bytes(x.to_be_bytes()))
+    }
+
+    #[must_use]
+    pub const fn to_data(self) -> u16 {
+        u16::from_be_bytes(self.0.to_be_bytes())
+    }
+}
+
+impl From<u16> for Power {
+    fn from(from: u16) -> Self {
+        Self::from_data(from)
+    }
+}
+
+impl From<Power> for u16 {
+    fn from(from: Power) -> Self {
+        from.to_data()
+    }
+}
+
+/// Formats the power in %, e.g. `-12.50 %`.
+impl fmt::Display for Power {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        centi::fmt(self.0, "%", f)
+    }
+}
@@ -0,0 +1,230 @@
+//! Linear temperature ramps.
+//!
+//! A [`Ramp`] describes a gradual change of the setpoint and
+//! expands into the timed sequence of setpoint commands.
+
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::{
+    io::{self, Read, Write},
+    thread,
+    time::Instant,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "std")]
+use crate::PilotOne;
+use crate::{Address, Command, Temperature, ValidationError, SETPOINT_RANGE};
+
+#[cfg(test)]
+mod tests;
+
+/// Resolution of the interpolation.
+const PARTS: u32 = 1_000_000;
+
+/// Linear change of the setpoint from `start` to `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RampParams"))]
+pub struct Ramp {
+    start: Temperature,
+    end: Temperature,
+    duration: Duration,
+    interval: Duration,
+}
+
+/// Unchecked fields of a deserialized [`Ramp`].
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RampParams {
+    start: Temperature,
+    end: Temperature,
+    duration: Duration,
+    interval: Duration,
+}
+
+/// Accepts only ramps within [`SETPOINT_RANGE`].
+#[cfg(feature = "serde")]
+impl TryFrom<RampParams> for Ramp {
+    type Error = ValidationError;
+    fn try_from(from: RampParams) -> Result<Self, ValidationError> {
+        let RampParams {
+            start,
+            end,
+            duration,
+            interval,
+        } = from;
+        Self::new(start, end, duration, interval)
+    }
+}
+
+impl Ramp {
+    /// A new setpoint is sent every `interval` until `end`
+    /// is reached after `duration`.
+    ///
+    /// Fails if `start` or `end` is outside of [`SETPOINT_RANGE`].
+    pub fn new(
+        start: Temperature,
+        end: Temperature,
+        duration: Duration,
+        interval: Duration,
+    ) -> Result<Self, ValidationError> {
+        for temp in [start, end] {
+            if !SETPOINT_RANGE.contains(&temp) {
+                return Err(ValidationError::OutOfRange {
+                    address: Address::SetpointTempControl,
+                    data: temp.to_data(),
+                });
+            }
+        }
+        Ok(Self {
+            start,
+            end,
+            duration,
+            interval,
+        })
+    }
+
+    #[must_use]
+    pub const fn start(&self) -> Temperature {
+        self.start
+    }
+
+    #[must_use]
+    pub const fn end(&self) -> Temperature {
+        self.end
+    }
+
+    #[must_use]
+    pub const fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    #[must_use]
+    pub const fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Interpolated setpoint `offset` after the start of the ramp.
+    #[must_use]
+    pub fn setpoint_at(&self, offset: Duration) -> Temperature {
+        if offset >= self.duration {
+            return self.end;
+        }
+        let start = i64::from(self.start.centi_celsius());
+        let delta = i64::from(self.end.centi_celsius()) - start;
+        // The offset is less than the duration.
+        let progress = offset.as_nanos() * u128::from(PARTS) / self.duration.as_nanos();
+        let progress = i64::try_from(progress).unwrap_or(PARTS.into());
+        let centi_celsius = start + delta * progress / i64::from(PARTS);
+        i16::try_from(centi_celsius).map_or(self.end, Temperature::from_centi_celsius)
+    }
+
+    /// Iterate over the setpoints.
+    ///
+    /// The first step is at offset zero and the last step
+    /// sets `end` at the full duration. A zero interval
+    /// yields only the final step.
+    #[must_use]
+    pub const fn steps(&self) -> Steps {
+        Steps {
+            ramp: *self,
+            index: Some(0),
+            done: false,
+        }
+    }
+
+    /// Iterate over the setpoint commands with their offsets.
+    pub fn commands(&self) -> impl Iterator<Item = (Duration, Command)> {
+        self.steps().map(|step| (step.offset, step.command()))
+    }
+}
+
+#[cfg(feature = "std")]
+impl Ramp {
+    /// Send the setpoints when they are due.
+    ///
+    /// Blocks until the ramp is complete.
+    pub fn run<T>(&self, client: &mut PilotOne<T>) -> io::Result<()>
+    where
+        T: Read + Write,
+    {
+        let started = Instant::now();
+        for step in self.steps() {
+            if let Some(wait) = step.offset.checked_sub(started.elapsed()) {
+                thread::sleep(wait);
+            }
+            client.set_setpoint(step.setpoint)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl Ramp {
+    /// Send the setpoints when they are due.
+    pub async fn run_async<T>(&self, client: &mut crate::AsyncPilotOne<T>) -> io::Result<()>
+    where
+        T: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+    {
+        let started = tokio::time::Instant::now();
+        for step in self.steps() {
+            tokio::time::sleep_until(started + step.offset).await;
+            client.set_setpoint(step.setpoint).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Setpoint of a [`Ramp`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Step {
+    /// Time after the start of the ramp
+    pub offset: Duration,
+    pub setpoint: Temperature,
+}
+
+impl Step {
+    #[must_use]
+    pub const fn command(&self) -> Command {
+        Command::set(Address::SetpointTempControl, self.setpoint.to_data())
+    }
+}
+
+/// Iterator returned by [`Ramp::steps`].
+#[derive(Debug, Clone)]
+pub struct Steps {
+    ramp: Ramp,
+    /// `None` if the index overflowed
+    index: Option<u32>,
+    done: bool,
+}
+
+impl Iterator for Steps {
+    type Item = Step;
+
+    fn next(&mut self) -> Option<Step> {
+        if self.done {
+            return None;
+        }
+        let offset = self
+            .index
+            .and_then(|index| self.ramp.interval.checked_mul(index))
+            .filter(|offset| !self.ramp.interval.is_zero() && *offset < self.ramp.duration);
+        let Some(offset) = offset else {
+            self.done = true;
+            return Some(Step {
+                offset: self.ramp.duration,
+                setpoint: self.ramp.end,
+            });
+        };
+        self.index = self.index.and_then(|index| index.checked_add(1));
+        Some(Step {
+            offset,
+            setpoint: self.ramp.setpoint_at(offset),
+        })
+    }
+}
@@ -0,0 +1,148 @@
+use super::*;
+
+const fn temp(centi_celsius: i16) -> Temperature {
+    Temperature::from_centi_celsius(centi_celsius)
+}
+
+#[test]
+fn interpolate_setpoints() {
+    let ramp = Ramp::new(
+        temp(2000),
+        temp(3000),
+        Duration::from_secs(100),
+        Duration::from_secs(30),
+    )
+    .unwrap();
+    assert_eq!(ramp.setpoint_at(Duration::ZERO), temp(2000));
+    assert_eq!(ramp.setpoint_at(Duration::from_secs(25)), temp(2250));
+    assert_eq!(ramp.setpoint_at(Duration::from_secs(100)), temp(3000));
+    assert_eq!(ramp.setpoint_at(Duration::from_secs(200)), temp(3000));
+
+    let ramp = Ramp::new(
+        temp(3000),
+        temp(-1000),
+        Duration::from_secs(4),
+        Duration::from_secs(1),
+    )
+    .unwrap();
+    assert_eq!(ramp.setpoint_at(Duration::from_secs(1)), temp(2000));
+    assert_eq!(ramp.setpoint_at(Duration::from_millis(3500)), temp(-500));
+}
+
+#[test]
+fn steps_end_at_target() {
+    let ramp = Ramp::new(
+        temp(2000),
+        temp(3000),
+        Duration::from_secs(100),
+        Duration::from_secs(30),
+    )
+    .unwrap();
+    let mut steps = ramp.steps();
+    for (secs, centi_celsius) in [(0, 2000), (30, 2300), (60, 2600), (90, 2900), (100, 3000)] {
+        assert_eq!(
+            steps.next(),
+            Some(Step {
+                offset: Duration::from_secs(secs),
+                setpoint: temp(centi_celsius)
+            })
+        );
+    }
+    assert_eq!(steps.next(), None);
+
+    let (offset, cmd) = ramp.commands().nth(1).unwrap();
+    assert_eq!(offset, Duration::from_secs(30));
+    assert_eq!(cmd, Command::set(Address::SetpointTempControl, 2300));
+}
+
+#[test]
+fn steps_end_after_index_overflow() {
+    // 1 ms over 60 days takes more steps than the index can count.
+    let duration = Duration::from_secs(60 * 24 * 60 * 60);
+    let interval = Duration::from_millis(1);
+    let ramp = Ramp::new(temp(2000), temp(3000), duration, interval).unwrap();
+    let mut steps = Steps {
+        index: Some(u32::MAX),
+        ..ramp.steps()
+    };
+    let last_offset = interval * u32::MAX;
+    assert_eq!(
+        steps.next(),
+        Some(Step {
+            offset: last_offset,
+            setpoint: ramp.setpoint_at(last_offset)
+        })
+    );
+    assert_eq!(
+        steps.next(),
+        Some(Step {
+            offset: duration,
+            setpoint: temp(3000)
+        })
+    );
+    assert_eq!(steps.next(), None);
+}
+
+#[test]
+fn degenerate_ramps() {
+    let ramp = Ramp::new(
+        temp(2000),
+        temp(3000),
+        Duration::from_secs(10),
+        Duration::ZERO,
+    )
+    .unwrap();
+    assert_eq!(ramp.steps().count(), 1);
+    assert_eq!(ramp.steps().next().unwrap().setpoint, temp(3000));
+
+    let ramp = Ramp::new(
+        temp(2000),
+        temp(3000),
+        Duration::ZERO,
+        Duration::from_secs(1),
+    )
+    .unwrap();
+    assert_eq!(ramp.steps().count(), 1);
+
+    assert_eq!(
+        Ramp::new(Temperature::MIN, temp(0), Duration::ZERO, Duration::ZERO),
+        Err(ValidationError::OutOfRange {
+            address: Address::SetpointTempControl,
+            data: 0x8000
+        })
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn run_ramp() {
+    let mut client = PilotOne::new(crate::mock::Mock::new(b"{S0007D0\r\n{S000BB8\r\n"))
+        .with_min_interval(Duration::ZERO);
+    let ramp = Ramp::new(
+        temp(2000),
+        temp(3000),
+        Duration::from_millis(20),
+        Duration::from_millis(20),
+    )
+    .unwrap();
+    ramp.run(&mut client).unwrap();
+    assert_eq!(client.get_ref().tx, b"{M0007D0\r\n{M000BB8\r\n");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn deserialize_checks_range() {
+    let ramp = Ramp::new(
+        temp(2000),
+        temp(3000),
+        Duration::from_secs(100),
+        Duration::from_secs(10),
+    )
+    .unwrap();
+    let json = serde_json::to_string(&ramp).unwrap();
+    assert_eq!(serde_json::from_str::<Ramp>(&json).unwrap(), ramp);
+
+    let json = json.replace("3000", "-20000");
+    let err = serde_json::from_str::<Ramp>(&json).unwrap_err();
+    assert!(err.is_data());
+}
@@ -0,0 +1,35 @@
+use std::time::{Duration, Instant};
+
+/// Default minimum time between two consecutive exchanges.
+///
+/// The device drops commands that follow the previous
+/// exchange too closely.
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Paces the master commands of a client.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RateLimiter {
+    pub(crate) min_interval: Duration,
+    last: Option<Instant>,
+}
+
+impl RateLimiter {
+    pub(crate) const fn new(min_interval: Duration) -> Self {
+        Self {
+            min_interval,
+            last: None,
+        }
+    }
+
+    /// Time to wait before the next command may be sent.
+    pub(crate) fn remaining(&self) -> Duration {
+        self.last.map_or(Duration::ZERO, |last| {
+            (last + self.min_interval).saturating_duration_since(Instant::now())
+        })
+    }
+
+    /// Mark the end of an exchange.
+    pub(crate) fn finish(&mut self) {
+        self.last = Some(Instant::now());
+    }
+}
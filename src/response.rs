@@ -0,0 +1,209 @@
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io::{Error, ErrorKind};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Address, AnalogSignal, Command, DigitalIo, ErrorCode, FirmwareVersion, InvalidValue, OnOff,
+    OperationLock, Power, Pressure, PumpSpeed, StatusFlags, TempControlMode, Temperature,
+    WarningCode,
+};
+
+/// Typed value of a slave reply.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Response {
+    SetpointTempControl(Temperature),
+    InternalTemp(Temperature),
+    PumpPressure(Pressure),
+    CurrentPower(Power),
+    ErrorReport(ErrorCode),
+    WarningMessage(WarningCode),
+    ProcessTemp(Temperature),
+    SetProcessTemp(Temperature),
+    Status(StatusFlags),
+    TempControlMode(TempControlMode),
+    TempControl(OnOff),
+    Circulation(OnOff),
+    OperationLock(OperationLock),
+    Degassing(OnOff),
+    ProcessTempActualSettingMode(u16),
+    UpperAlarmLimit(Temperature),
+    LowerAlarmLimit(Temperature),
+    UpperDeltaLimit(Temperature),
+    LowerDeltaLimit(Temperature),
+    PumpSpeed(PumpSpeed),
+    PumpSpeedSetpoint(PumpSpeed),
+    ComGateDigitalInputs(DigitalIo),
+    ComGateDigitalOutputs(DigitalIo),
+    ComGateAnalogInput(AnalogSignal),
+    ComGateAnalogOutput(AnalogSignal),
+    EcsStandby(OnOff),
+    DeviceType(u16),
+    FirmwareVersion(FirmwareVersion),
+    SerialNumberHigh(u16),
+    SerialNumberLow(u16),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DecodeError {
+    /// Unknown command address
+    Address(u8),
+    /// Missing command data
+    MissingData,
+    /// Invalid command data
+    InvalidValue(InvalidValue),
+}
+
+impl From<InvalidValue> for DecodeError {
+    fn from(from: InvalidValue) -> Self {
+        Self::InvalidValue(from)
+    }
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::Address(x) => write!(f, "Unknown command address: {x:#04X}"),
+            Self::MissingData => write!(f, "Missing command data"),
+            Self::InvalidValue(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
+
+#[cfg(feature = "std")]
+impl From<DecodeError> for Error {
+    fn from(e: DecodeError) -> Error {
+        Error::new(ErrorKind::InvalidData, e)
+    }
+}
+
+impl Response {
+    /// Decode the data of a command.
+    pub fn decode(cmd: &Command) -> Result<Self, DecodeError> {
+        let address = Address::from_u8(cmd.address).ok_or(DecodeError::Address(cmd.address))?;
+        let data = cmd.data.ok_or(DecodeError::MissingData)?;
+        Self::from_data(address, data)
+    }
+
+    pub fn from_data(address: Address, data: u16) -> Result<Self, DecodeError> {
+        let res = match address {
+            Address::SetpointTempControl => Self::SetpointTempControl(data.into()),
+            Address::InternalTemp => Self::InternalTemp(data.into()),
+            Address::PumpPressure => Self::PumpPressure(data.into()),
+            Address::CurrentPower => Self::CurrentPower(data.into()),
+            Address::ErrorReport => Self::ErrorReport(data.into()),
+            Address::WarningMessage => Self::WarningMessage(data.into()),
+            Address::ProcessTemp => Self::ProcessTemp(data.into()),
+            Address::SetProcessTemp => Self::SetProcessTemp(data.into()),
+            Address::Status => Self::Status(data.into()),
+            Address::TempControlMode => Self::TempControlMode(data.try_into()?),
+            Address::TempControl => Self::TempControl(data.try_into()?),
+            Address::Circulation => Self::Circulation(data.try_into()?),
+            Address::OperationLock => Self::OperationLock(data.try_into()?),
+            Address::Degassing => Self::Degassing(data.try_into()?),
+            Address::ProcessTempActualSettingMode => Self::ProcessTempActualSettingMode(data),
+            Address::UpperAlarmLimit => Self::UpperAlarmLimit(data.into()),
+            Address::LowerAlarmLimit => Self::LowerAlarmLimit(data.into()),
+            Address::UpperDeltaLimit => Self::UpperDeltaLimit(data.into()),
+            Address::LowerDeltaLimit => Self::LowerDeltaLimit(data.into()),
+            Address::PumpSpeed => Self::PumpSpeed(data.into()),
+            Address::PumpSpeedSetpoint => Self::PumpSpeedSetpoint(data.into()),
+            Address::ComGateDigitalInputs => Self::ComGateDigitalInputs(data.into()),
+            Address::ComGateDigitalOutputs => Self::ComGateDigitalOutputs(data.into()),
+            Address::ComGateAnalogInput => Self::ComGateAnalogInput(data.into()),
+            Address::ComGateAnalogOutput => Self::ComGateAnalogOutput(data.into()),
+            Address::EcsStandby => Self::EcsStandby(data.try_into()?),
+            Address::DeviceType => Self::DeviceType(data),
+            Address::FirmwareVersion => Self::FirmwareVersion(data.into()),
+            Address::SerialNumberHigh => Self::SerialNumberHigh(data),
+            Address::SerialNumberLow => Self::SerialNumberLow(data),
+        };
+        Ok(res)
+    }
+
+    #[must_use]
+    pub const fn address(&self) -> Address {
+        match self {
+            Self::SetpointTempControl(_) => Address::SetpointTempControl,
+            Self::InternalTemp(_) => Address::InternalTemp,
+            Self::PumpPressure(_) => Address::PumpPressure,
+            Self::CurrentPower(_) => Address::CurrentPower,
+            Self::ErrorReport(_) => Address::ErrorReport,
+            Self::WarningMessage(_) => Address::WarningMessage,
+            Self::ProcessTemp(_) => Address::ProcessTemp,
+            Self::SetProcessTemp(_) => Address::SetProcessTemp,
+            Self::Status(_) => Address::Status,
+            Self::TempControlMode(_) => Address::TempControlMode,
+            Self::TempControl(_) => Address::TempControl,
+            Self::Circulation(_) => Address::Circulation,
+            Self::OperationLock(_) => Address::OperationLock,
+            Self::Degassing(_) => Address::Degassing,
+            Self::ProcessTempActualSettingMode(_) => Address::ProcessTempActualSettingMode,
+            Self::UpperAlarmLimit(_) => Address::UpperAlarmLimit,
+            Self::LowerAlarmLimit(_) => Address::LowerAlarmLimit,
+            Self::UpperDeltaLimit(_) => Address::UpperDeltaLimit,
+            Self::LowerDeltaLimit(_) => Address::LowerDeltaLimit,
+            Self::PumpSpeed(_) => Address::PumpSpeed,
+            Self::PumpSpeedSetpoint(_) => Address::PumpSpeedSetpoint,
+            Self::ComGateDigitalInputs(_) => Address::ComGateDigitalInputs,
+            Self::ComGateDigitalOutputs(_) => Address::ComGateDigitalOutputs,
+            Self::ComGateAnalogInput(_) => Address::ComGateAnalogInput,
+            Self::ComGateAnalogOutput(_) => Address::ComGateAnalogOutput,
+            Self::EcsStandby(_) => Address::EcsStandby,
+            Self::DeviceType(_) => Address::DeviceType,
+            Self::FirmwareVersion(_) => Address::FirmwareVersion,
+            Self::SerialNumberHigh(_) => Address::SerialNumberHigh,
+            Self::SerialNumberLow(_) => Address::SerialNumberLow,
+        }
+    }
+
+    /// Raw data word.
+    #[must_use]
+    pub fn data(&self) -> u16 {
+        match *self {
+            Self::SetpointTempControl(x)
+            | Self::InternalTemp(x)
+            | Self::ProcessTemp(x)
+            | Self::SetProcessTemp(x)
+            | Self::UpperAlarmLimit(x)
+            | Self::LowerAlarmLimit(x)
+            | Self::UpperDeltaLimit(x)
+            | Self::LowerDeltaLimit(x) => x.into(),
+            Self::Status(x) => x.into(),
+            Self::ErrorReport(x) => x.into(),
+            Self::WarningMessage(x) => x.into(),
+            Self::TempControlMode(x) => x.into(),
+            Self::TempControl(x)
+            | Self::Circulation(x)
+            | Self::Degassing(x)
+            | Self::EcsStandby(x) => x.into(),
+            Self::OperationLock(x) => x.into(),
+            Self::ProcessTempActualSettingMode(x)
+            | Self::DeviceType(x)
+            | Self::SerialNumberHigh(x)
+            | Self::SerialNumberLow(x) => x,
+            Self::FirmwareVersion(x) => x.into(),
+            Self::PumpPressure(x) => x.into(),
+            Self::CurrentPower(x) => x.into(),
+            Self::PumpSpeed(x) | Self::PumpSpeedSetpoint(x) => x.into(),
+            Self::ComGateDigitalInputs(x) | Self::ComGateDigitalOutputs(x) => x.into(),
+            Self::ComGateAnalogInput(x) | Self::ComGateAnalogOutput(x) => x.into(),
+        }
+    }
+}
+
+impl TryFrom<Command> for Response {
+    type Error = DecodeError;
+    fn try_from(from: Command) -> Result<Self, DecodeError> {
+        Self::decode(&from)
+    }
+}
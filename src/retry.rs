@@ -0,0 +1,122 @@
+use std::{
+    fmt,
+    io::{Error, ErrorKind},
+    time::Duration,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::ParseError;
+
+#[cfg(test)]
+mod tests;
+
+/// The device did not reply in time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TimeoutError {
+    /// The exceeded timeout
+    pub timeout: Duration,
+}
+
+impl fmt::Display for TimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "No reply from device within {:?}", self.timeout)
+    }
+}
+
+impl std::error::Error for TimeoutError {}
+
+impl From<TimeoutError> for Error {
+    fn from(e: TimeoutError) -> Error {
+        Error::new(ErrorKind::TimedOut, e)
+    }
+}
+
+/// Timeouts of a client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Timeouts {
+    /// Maximum time of a single exchange (sending
+    /// the command and receiving the reply)
+    pub exchange: Duration,
+}
+
+impl Timeouts {
+    pub const DEFAULT: Self = Self {
+        exchange: Duration::from_secs(1),
+    };
+}
+
+impl Default for Timeouts {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Repetition of failed exchanges.
+///
+/// An exchange is repeated if the device did not reply
+/// in time or if the reply was corrupted. Replies to a
+/// different request are not repeated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RetryPolicy {
+    /// Number of repetitions after the first attempt
+    pub max_retries: u32,
+    /// Delay before the first repetition
+    pub backoff: Duration,
+    /// Factor the delay is multiplied with after each repetition
+    pub backoff_factor: u32,
+}
+
+impl RetryPolicy {
+    /// Never repeat an exchange.
+    pub const NONE: Self = Self {
+        max_retries: 0,
+        backoff: Duration::ZERO,
+        backoff_factor: 1,
+    };
+
+    /// Repeat an exchange up to `max_retries` times without delay.
+    #[must_use]
+    pub const fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            ..Self::NONE
+        }
+    }
+
+    #[must_use]
+    pub const fn with_backoff(mut self, backoff: Duration, factor: u32) -> Self {
+        self.backoff = backoff;
+        self.backoff_factor = factor;
+        self
+    }
+
+    /// Delay before repetition number `retry` (starting at `0`).
+    #[must_use]
+    pub const fn delay(&self, retry: u32) -> Duration {
+        let factor = self.backoff_factor.saturating_pow(retry);
+        self.backoff.saturating_mul(factor)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
+/// Returns `true` if the exchange that failed with `err` should be repeated.
+pub(crate) fn is_retryable(err: &Error) -> bool {
+    match err.kind() {
+        ErrorKind::TimedOut | ErrorKind::WouldBlock => true,
+        // Only corrupted frames, not replies that don't match the request.
+        ErrorKind::InvalidData => {
+            matches!(err.get_ref(), Some(e) if e.is::<ParseError>())
+        }
+        _ => false,
+    }
+}
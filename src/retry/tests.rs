@@ -0,0 +1,38 @@
+use super::*;
+
+#[test]
+fn backoff_delay() {
+    let policy = RetryPolicy::new(3).with_backoff(Duration::from_millis(10), 2);
+    assert_eq!(policy.delay(0), Duration::from_millis(10));
+    assert_eq!(policy.delay(1), Duration::from_millis(20));
+    assert_eq!(policy.delay(2), Duration::from_millis(40));
+    assert_eq!(policy.delay(100), Duration::from_millis(10) * u32::MAX);
+    assert_eq!(RetryPolicy::new(3).delay(2), Duration::ZERO);
+}
+
+#[test]
+fn retryable_errors() {
+    assert!(is_retryable(
+        &TimeoutError {
+            timeout: Duration::ZERO
+        }
+        .into()
+    ));
+    assert!(is_retryable(
+        &crate::ParseError::Sender {
+            offset: 1,
+            byte: b'X'
+        }
+        .into()
+    ));
+    assert!(!is_retryable(&Error::from(ErrorKind::BrokenPipe)));
+    let query = crate::Command::query(crate::Address::InternalTemp);
+    assert!(!is_retryable(
+        &crate::ResponseMismatch {
+            request: query,
+            response: query,
+        }
+        .into()
+    ));
+    assert!(!is_retryable(&Error::from(ErrorKind::InvalidData)));
+}
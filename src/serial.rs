@@ -0,0 +1,57 @@
+//! RS232 transport based on the [`serialport`] crate.
+
+use std::{io::Result, time::Duration};
+
+use serialport::{DataBits, FlowControl, Parity, SerialPort, SerialPortBuilder, StopBits};
+
+use crate::{Address, PilotOne};
+
+#[cfg(test)]
+mod tests;
+
+/// Baud rate of the Pilot ONE.
+pub const BAUD_RATE: u32 = 9600;
+
+/// Default read and write timeout.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Client connected to a serial port.
+pub type SerialPilotOne = PilotOne<Box<dyn SerialPort>>;
+
+/// Port settings required by the Pilot ONE (9600 8N1, no flow control).
+#[must_use]
+pub fn builder(path: &str) -> SerialPortBuilder {
+    serialport::new(path, BAUD_RATE)
+        .data_bits(DataBits::Eight)
+        .parity(Parity::None)
+        .stop_bits(StopBits::One)
+        .flow_control(FlowControl::None)
+        .timeout(DEFAULT_TIMEOUT)
+}
+
+/// Open the serial port at `path`.
+pub fn open(path: &str) -> Result<SerialPilotOne> {
+    Ok(PilotOne::new(builder(path).open()?))
+}
+
+/// Find the first available serial port with a Pilot ONE attached.
+pub fn detect() -> Result<Option<(String, SerialPilotOne)>> {
+    let ports = serialport::available_ports()?;
+    Ok(detect_from(ports.into_iter().map(|p| p.port_name)))
+}
+
+/// Find the first of the `candidates` with a Pilot ONE attached.
+///
+/// Each port is probed by querying the internal temperature,
+/// which has no effect on the device.
+pub fn detect_from<I>(candidates: I) -> Option<(String, SerialPilotOne)>
+where
+    I: IntoIterator,
+    I::Item: Into<String>,
+{
+    candidates.into_iter().map(Into::into).find_map(|path| {
+        let mut client = open(&path).ok()?;
+        client.query(Address::InternalTemp).ok()?;
+        Some((path, client))
+    })
+}
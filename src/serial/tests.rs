@@ -0,0 +1,6 @@
+use super::*;
+
+#[test]
+fn detect_without_device() {
+    assert!(detect_from(["/dev/huber-pilot-one-does-not-exist"]).is_none());
+}
@@ -0,0 +1,186 @@
+//! Software implementation of the slave side for testing.
+//!
+//! The [`Simulator`] answers master commands from a register map.
+//! With the `std` feature it implements [`std::io::Read`] and
+//! [`std::io::Write`] so it can be used as transport of a client.
+
+#[cfg(feature = "std")]
+use std::{
+    collections::VecDeque,
+    io::{self, Read, Write},
+};
+
+use crate::{
+    frame::FrameBuf, Address, Command, FirmwareVersion, FrameFormat, OnOff, Sender,
+    TempControlMode, Temperature,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Simulated Pilot ONE.
+#[derive(Debug, Clone)]
+pub struct Simulator {
+    registers: [Option<u16>; 256],
+    format: FrameFormat,
+    frame: FrameBuf,
+    #[cfg(feature = "std")]
+    tx: VecDeque<u8>,
+}
+
+impl Default for Simulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Simulator {
+    /// Simulator with all known addresses at their power-on defaults.
+    #[must_use]
+    pub fn new() -> Self {
+        let mut sim = Self::empty();
+        let room_temp = Temperature::from_centi_celsius(2000).to_data();
+        for address in [
+            Address::SetpointTempControl,
+            Address::InternalTemp,
+            Address::ProcessTemp,
+            Address::SetProcessTemp,
+        ] {
+            sim.set_register(address, room_temp);
+        }
+        sim.set_register(Address::ErrorReport, 0);
+        sim.set_register(Address::Status, 0);
+        sim.set_register(Address::WarningMessage, 0);
+        sim.set_register(Address::TempControlMode, TempControlMode::Internal.into());
+        sim.set_register(Address::ProcessTempActualSettingMode, 0);
+        sim.set_register(
+            Address::UpperAlarmLimit,
+            Temperature::from_centi_celsius(10_000).to_data(),
+        );
+        sim.set_register(
+            Address::LowerAlarmLimit,
+            Temperature::from_centi_celsius(-2_000).to_data(),
+        );
+        for address in [Address::UpperDeltaLimit, Address::LowerDeltaLimit] {
+            sim.set_register(address, Temperature::from_centi_celsius(1_000).to_data());
+        }
+        sim.set_register(Address::PumpPressure, 0);
+        sim.set_register(Address::CurrentPower, 0);
+        sim.set_register(Address::PumpSpeed, 0);
+        sim.set_register(Address::PumpSpeedSetpoint, 0);
+        for address in [
+            Address::ComGateDigitalInputs,
+            Address::ComGateDigitalOutputs,
+            Address::ComGateAnalogInput,
+            Address::ComGateAnalogOutput,
+        ] {
+            sim.set_register(address, 0);
+        }
+        sim.set_register(Address::EcsStandby, OnOff::Off.into());
+        sim.set_register(Address::DeviceType, 0);
+        sim.set_register(Address::FirmwareVersion, FirmwareVersion::new(1, 0).into());
+        sim.set_register(Address::SerialNumberHigh, 0);
+        sim.set_register(Address::SerialNumberLow, 0);
+        for address in [
+            Address::TempControl,
+            Address::Circulation,
+            Address::OperationLock,
+            Address::Degassing,
+        ] {
+            sim.set_register(address, OnOff::Off.into());
+        }
+        sim
+    }
+
+    /// Simulator without any registers.
+    #[must_use]
+    pub const fn empty() -> Self {
+        Self {
+            registers: [None; 256],
+            format: FrameFormat::Plain,
+            frame: FrameBuf::new(FrameFormat::Plain),
+            #[cfg(feature = "std")]
+            tx: VecDeque::new(),
+        }
+    }
+
+    /// Expect and send frames in the given format.
+    #[must_use]
+    pub const fn with_frame_format(mut self, format: FrameFormat) -> Self {
+        self.format = format;
+        self.frame = FrameBuf::new(format);
+        self
+    }
+
+    #[must_use]
+    pub fn register(&self, address: Address) -> Option<u16> {
+        self.registers[usize::from(u8::from(address))]
+    }
+
+    /// Set a register, regardless if it can be written by a master.
+    pub fn set_register(&mut self, address: Address, data: u16) {
+        self.set_raw_register(address.into(), data);
+    }
+
+    pub fn set_raw_register(&mut self, address: u8, data: u16) {
+        self.registers[usize::from(address)] = Some(data);
+    }
+
+    pub fn remove_raw_register(&mut self, address: u8) {
+        self.registers[usize::from(address)] = None;
+    }
+
+    /// Handle a master command and return the reply.
+    ///
+    /// Writing read-only registers has no effect and the reply
+    /// contains the current value. Slave frames and commands for
+    /// unknown registers are not answered.
+    pub fn handle(&mut self, cmd: Command) -> Option<Command> {
+        if cmd.sender != Sender::Master {
+            return None;
+        }
+        let index = usize::from(cmd.address);
+        let current = self.registers[index]?;
+        let writable = Address::from_u8(cmd.address).map_or(true, Address::is_writable);
+        let data = match cmd.data {
+            Some(data) if writable => {
+                self.registers[index] = Some(data);
+                data
+            }
+            _ => current,
+        };
+        Some(Command {
+            sender: Sender::Slave,
+            address: cmd.address,
+            data: Some(data),
+        })
+    }
+
+    /// Feed a single received byte and return the reply
+    /// as soon as a complete master frame has been received.
+    pub fn push(&mut self, byte: u8) -> Option<Command> {
+        self.frame.push(byte)?.ok().and_then(|cmd| self.handle(cmd))
+    }
+}
+
+#[cfg(feature = "std")]
+impl Read for Simulator {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.tx.read(buf)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Write for Simulator {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        for byte in buf {
+            if let Some(reply) = self.push(*byte) {
+                self.tx.extend(self.format.encode(reply).iter());
+            }
+        }
+        Ok(buf.len())
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+use super::*;
+
+#[test]
+fn answer_queries() {
+    let mut sim = Simulator::new();
+    let reply = sim.handle(Command::query(Address::InternalTemp)).unwrap();
+    assert_eq!(reply.sender, Sender::Slave);
+    assert_eq!(reply.address, 0x01);
+    assert_eq!(reply.data, Some(2000));
+}
+
+#[test]
+fn write_registers() {
+    let mut sim = Simulator::new();
+    let reply = sim
+        .handle(Command::set(Address::SetpointTempControl, 0x0E42))
+        .unwrap();
+    assert_eq!(reply.data, Some(0x0E42));
+    assert_eq!(sim.register(Address::SetpointTempControl), Some(0x0E42));
+}
+
+#[test]
+fn ignore_writes_to_read_only_registers() {
+    let mut sim = Simulator::new();
+    let reply = sim.handle(Command::set(Address::InternalTemp, 0)).unwrap();
+    assert_eq!(reply.data, Some(2000));
+    assert_eq!(sim.register(Address::InternalTemp), Some(2000));
+}
+
+#[test]
+fn ignore_unknown_registers_and_slave_frames() {
+    let mut sim = Simulator::empty();
+    assert!(sim.handle(Command::query(Address::InternalTemp)).is_none());
+    sim.set_raw_register(0x7F, 1);
+    let cmd = Command {
+        sender: Sender::Master,
+        address: 0x7F,
+        data: None,
+    };
+    assert_eq!(sim.handle(cmd).unwrap().data, Some(1));
+    sim.remove_raw_register(0x7F);
+    assert!(sim.handle(cmd).is_none());
+    let cmd = Command {
+        sender: Sender::Slave,
+        address: 0x01,
+        data: None,
+    };
+    assert!(Simulator::new().handle(cmd).is_none());
+}
+
+#[test]
+fn push_bytes() {
+    let mut sim = Simulator::new();
+    let frame = Command::query(Address::TempControl).into_bytes();
+    for byte in &frame[..9] {
+        assert!(sim.push(*byte).is_none());
+    }
+    assert_eq!(sim.push(frame[9]).unwrap().data, Some(0));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn use_as_transport() {
+    use crate::{PilotOne, Thermostat};
+
+    let mut sim = Simulator::new();
+    sim.set_register(Address::InternalTemp, 2500);
+    let mut thermostat = Thermostat::with_client(PilotOne::new(sim));
+    thermostat.set_setpoint_celsius(36.5).unwrap();
+    thermostat.start().unwrap();
+    assert!(thermostat.is_running().unwrap());
+    assert!((thermostat.internal_temperature().unwrap() - 25.0).abs() < f64::EPSILON);
+    let sim = thermostat.into_client().into_inner();
+    assert_eq!(sim.register(Address::SetpointTempControl), Some(3650));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn checksum_frame_format() {
+    use crate::{FrameFormat, PilotOne};
+
+    let sim = Simulator::new().with_frame_format(FrameFormat::Checksum);
+    let mut client = PilotOne::new(sim).with_frame_format(FrameFormat::Checksum);
+    assert_eq!(client.query(Address::SetpointTempControl).unwrap(), 2000);
+}
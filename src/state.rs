@@ -0,0 +1,178 @@
+use std::{
+    collections::BTreeMap,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    Address, Command, DecodeError, ErrorCode, OnOff, Power, PumpSpeed, Response, Sender,
+    StatusFlags, TempControlMode, Temperature, WarningCode,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Last known value of an address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sample {
+    pub response: Response,
+    /// Time the value has been received
+    pub received: Instant,
+}
+
+/// Last known values of a device.
+///
+/// The state is updated with the decoded slave replies, e.g.
+/// from a [`Poller`](crate::poller::Poller), and answers queries
+/// without a round-trip to the device. Cloning the state yields
+/// a consistent snapshot.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeviceState {
+    samples: BTreeMap<u8, Sample>,
+}
+
+impl DeviceState {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            samples: BTreeMap::new(),
+        }
+    }
+
+    /// Store a value that has just been received.
+    pub fn update(&mut self, response: Response) {
+        self.update_at(response, Instant::now());
+    }
+
+    /// Store a value that has been received at `received`.
+    ///
+    /// Values that are older than the current one are ignored.
+    pub fn update_at(&mut self, response: Response, received: Instant) {
+        let key = u8::from(response.address());
+        if self
+            .samples
+            .get(&key)
+            .is_some_and(|sample| sample.received > received)
+        {
+            return;
+        }
+        self.samples.insert(key, Sample { response, received });
+    }
+
+    /// Decode and store a received slave reply.
+    ///
+    /// Master commands are ignored.
+    pub fn ingest(&mut self, cmd: &Command) -> Result<(), DecodeError> {
+        if cmd.sender != Sender::Slave {
+            return Ok(());
+        }
+        self.update(Response::decode(cmd)?);
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn get(&self, address: Address) -> Option<&Sample> {
+        self.samples.get(&u8::from(address))
+    }
+
+    /// Time since the value of `address` has been received.
+    #[must_use]
+    pub fn age(&self, address: Address) -> Option<Duration> {
+        self.get(address).map(|sample| sample.received.elapsed())
+    }
+
+    /// Returns `true` if the value of `address` is unknown
+    /// or older than `max_age`.
+    #[must_use]
+    pub fn is_stale(&self, address: Address, max_age: Duration) -> bool {
+        self.age(address).map_or(true, |age| age > max_age)
+    }
+
+    /// Forget all values.
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    fn response(&self, address: Address) -> Option<Response> {
+        self.get(address).map(|sample| sample.response)
+    }
+
+    #[must_use]
+    pub fn setpoint(&self) -> Option<Temperature> {
+        match self.response(Address::SetpointTempControl)? {
+            Response::SetpointTempControl(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn internal_temp(&self) -> Option<Temperature> {
+        match self.response(Address::InternalTemp)? {
+            Response::InternalTemp(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn process_temp(&self) -> Option<Temperature> {
+        match self.response(Address::ProcessTemp)? {
+            Response::ProcessTemp(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn power(&self) -> Option<Power> {
+        match self.response(Address::CurrentPower)? {
+            Response::CurrentPower(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn pump_speed(&self) -> Option<PumpSpeed> {
+        match self.response(Address::PumpSpeed)? {
+            Response::PumpSpeed(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn temp_control_mode(&self) -> Option<TempControlMode> {
+        match self.response(Address::TempControlMode)? {
+            Response::TempControlMode(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn temp_control(&self) -> Option<OnOff> {
+        match self.response(Address::TempControl)? {
+            Response::TempControl(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn status(&self) -> Option<StatusFlags> {
+        match self.response(Address::Status)? {
+            Response::Status(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn error_code(&self) -> Option<ErrorCode> {
+        match self.response(Address::ErrorReport)? {
+            Response::ErrorReport(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn warning_code(&self) -> Option<WarningCode> {
+        match self.response(Address::WarningMessage)? {
+            Response::WarningMessage(x) => Some(x),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use super::*;
+
+#[test]
+fn update_and_get() {
+    let mut state = DeviceState::new();
+    assert_eq!(state.internal_temp(), None);
+    let temp = Temperature::from_centi_celsius(2500);
+    state.update(Response::InternalTemp(temp));
+    state.update(Response::ErrorReport(ErrorCode::NoError));
+    assert_eq!(state.internal_temp(), Some(temp));
+    assert_eq!(state.error_code(), Some(ErrorCode::NoError));
+    assert_eq!(state.setpoint(), None);
+    state.clear();
+    assert_eq!(state.internal_temp(), None);
+}
+
+#[test]
+fn ingest_replies() {
+    let mut state = DeviceState::new();
+    state.ingest(&"{S130001\r\n".parse().unwrap()).unwrap();
+    assert_eq!(state.temp_control_mode(), Some(TempControlMode::Process));
+    // Master commands are not values of the device
+    state
+        .ingest(&Command::set(Address::SetpointTempControl, 0))
+        .unwrap();
+    assert_eq!(state.setpoint(), None);
+    let err = state.ingest(&"{S140002\r\n".parse().unwrap()).unwrap_err();
+    assert_eq!(err, DecodeError::InvalidValue(crate::InvalidValue(2)));
+}
+
+#[test]
+fn staleness() {
+    let mut state = DeviceState::new();
+    let max_age = Duration::from_secs(10);
+    assert!(state.is_stale(Address::SetpointTempControl, max_age));
+    let now = Instant::now();
+    let setpoint = Response::SetpointTempControl(Temperature::from_centi_celsius(3650));
+    state.update_at(setpoint, now);
+    assert!(!state.is_stale(Address::SetpointTempControl, max_age));
+    assert!(state.age(Address::SetpointTempControl).unwrap() < max_age);
+
+    let Some(old) = now.checked_sub(Duration::from_secs(60)) else {
+        return;
+    };
+    // Older values don't replace newer ones
+    state.update_at(
+        Response::SetpointTempControl(Temperature::from_centi_celsius(0)),
+        old,
+    );
+    assert_eq!(
+        state.get(Address::SetpointTempControl).unwrap().received,
+        now
+    );
+    state.clear();
+    state.update_at(setpoint, old);
+    assert!(state.is_stale(Address::SetpointTempControl, max_age));
+}
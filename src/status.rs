@@ -0,0 +1,157 @@
+//! Payload of the status address.
+
+use core::{fmt, ops};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// Bit-packed status word of the device.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct StatusFlags(u16);
+
+impl StatusFlags {
+    /// Temperature control is active
+    pub const TEMP_CONTROL: Self = Self(1 << 0);
+    /// Circulation is active
+    pub const CIRCULATION: Self = Self(1 << 1);
+    /// An error is present
+    pub const ERROR: Self = Self(1 << 2);
+    /// A warning is present
+    pub const WARNING: Self = Self(1 << 3);
+    /// The keypad is locked
+    pub const KEYPAD_LOCKED: Self = Self(1 << 4);
+    /// The process temperature is controlled
+    pub const PROCESS_CONTROL: Self = Self(1 << 5);
+
+    const NAMES: [(Self, &'static str); 6] = [
+        (Self::TEMP_CONTROL, "temp control"),
+        (Self::CIRCULATION, "circulation"),
+        (Self::ERROR, "error"),
+        (Self::WARNING, "warning"),
+        (Self::KEYPAD_LOCKED, "keypad locked"),
+        (Self::PROCESS_CONTROL, "process control"),
+    ];
+
+    #[must_use]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Unknown bits are preserved.
+    #[must_use]
+    pub const fn from_bits(bits: u16) -> Self {
+        Self(bits)
+    }
+
+    #[must_use]
+    pub const fn bits(self) -> u16 {
+        self.0
+    }
+
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if all flags of `other` are set.
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    #[must_use]
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    #[must_use]
+    pub const fn is_temp_control_active(self) -> bool {
+        self.contains(Self::TEMP_CONTROL)
+    }
+
+    #[must_use]
+    pub const fn is_circulation_active(self) -> bool {
+        self.contains(Self::CIRCULATION)
+    }
+
+    #[must_use]
+    pub const fn has_error(self) -> bool {
+        self.contains(Self::ERROR)
+    }
+
+    #[must_use]
+    pub const fn has_warning(self) -> bool {
+        self.contains(Self::WARNING)
+    }
+
+    #[must_use]
+    pub const fn is_keypad_locked(self) -> bool {
+        self.contains(Self::KEYPAD_LOCKED)
+    }
+
+    #[must_use]
+    pub const fn is_process_control(self) -> bool {
+        self.contains(Self::PROCESS_CONTROL)
+    }
+}
+
+impl ops::BitOr for StatusFlags {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+impl ops::BitAnd for StatusFlags {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl From<u16> for StatusFlags {
+    fn from(from: u16) -> Self {
+        Self::from_bits(from)
+    }
+}
+
+impl From<StatusFlags> for u16 {
+    fn from(from: StatusFlags) -> Self {
+        from.bits()
+    }
+}
+
+/// Lists the names of the set flags, e.g. `temp control, circulation`.
+///
+/// Unknown bits are appended in hex and an empty
+/// status word is formatted as `none`.
+impl fmt::Display for StatusFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "none");
+        }
+        let mut rest = *self;
+        let mut separator = "";
+        for (flag, name) in Self::NAMES {
+            if self.contains(flag) {
+                write!(f, "{separator}{name}")?;
+                separator = ", ";
+                rest = rest.difference(flag);
+            }
+        }
+        if !rest.is_empty() {
+            write!(f, "{separator}{:#06X}", rest.0)?;
+        }
+        Ok(())
+    }
+}
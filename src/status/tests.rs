@@ -0,0 +1,42 @@
+use super::*;
+use crate::{Address, Response};
+
+#[test]
+fn named_flags() {
+    let status = StatusFlags::from_bits(0b1_0011);
+    assert!(status.is_temp_control_active());
+    assert!(status.is_circulation_active());
+    assert!(!status.has_error());
+    assert!(!status.has_warning());
+    assert!(status.is_keypad_locked());
+    assert!(!status.is_process_control());
+    assert!(status.contains(StatusFlags::TEMP_CONTROL | StatusFlags::CIRCULATION));
+    assert!(!status.contains(StatusFlags::TEMP_CONTROL | StatusFlags::ERROR));
+    assert_eq!(
+        status & StatusFlags::KEYPAD_LOCKED,
+        StatusFlags::KEYPAD_LOCKED
+    );
+    assert!(StatusFlags::default().is_empty());
+}
+
+#[test]
+fn decode_status() {
+    assert_eq!(
+        Response::from_data(Address::Status, 0x0024),
+        Ok(Response::Status(
+            StatusFlags::ERROR | StatusFlags::PROCESS_CONTROL
+        ))
+    );
+    assert_eq!(u16::from(StatusFlags::WARNING), 0x0008);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn display_status() {
+    assert_eq!(StatusFlags::empty().to_string(), "none");
+    assert_eq!(
+        (StatusFlags::TEMP_CONTROL | StatusFlags::CIRCULATION).to_string(),
+        "temp control, circulation"
+    );
+    assert_eq!(StatusFlags::from_bits(0x8004).to_string(), "error, 0x8000");
+}
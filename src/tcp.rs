@@ -0,0 +1,120 @@
+//! Transport for Pilot ONE units with a Com.G@te Ethernet interface.
+//!
+//! The Com.G@te exposes the PB protocol on a raw TCP socket.
+
+use std::{
+    io::Result,
+    net::{IpAddr, SocketAddr, TcpStream},
+    time::Duration,
+};
+
+use socket2::{SockRef, TcpKeepalive};
+
+use crate::{PilotOne, Timeouts};
+
+#[cfg(feature = "tokio")]
+use crate::AsyncPilotOne;
+
+#[cfg(test)]
+mod tests;
+
+/// TCP port of the PB protocol.
+pub const DEFAULT_PORT: u16 = 8101;
+
+/// Default timeout for connecting and for each exchange.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Default idle time before TCP keep-alive probes are sent.
+pub const DEFAULT_KEEPALIVE: Duration = Duration::from_secs(10);
+
+/// Opens connections to a Com.G@te.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Connector {
+    addr: SocketAddr,
+    timeout: Duration,
+    keepalive: Duration,
+}
+
+impl Connector {
+    /// Connect to `ip` on the [`DEFAULT_PORT`].
+    #[must_use]
+    pub const fn new(ip: IpAddr) -> Self {
+        Self::with_addr(SocketAddr::new(ip, DEFAULT_PORT))
+    }
+
+    #[must_use]
+    pub const fn with_addr(addr: SocketAddr) -> Self {
+        Self {
+            addr,
+            timeout: DEFAULT_TIMEOUT,
+            keepalive: DEFAULT_KEEPALIVE,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_keepalive(mut self, keepalive: Duration) -> Self {
+        self.keepalive = keepalive;
+        self
+    }
+
+    #[must_use]
+    pub const fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Open a blocking connection.
+    pub fn connect(&self) -> Result<PilotOne<TcpStream>> {
+        let stream = TcpStream::connect_timeout(&self.addr, self.timeout)?;
+        stream.set_read_timeout(Some(self.timeout))?;
+        stream.set_write_timeout(Some(self.timeout))?;
+        stream.set_nodelay(true)?;
+        self.set_keepalive(&SockRef::from(&stream))?;
+        Ok(PilotOne::new(stream).with_timeouts(self.timeouts()))
+    }
+
+    /// Open an asynchronous connection.
+    #[cfg(feature = "tokio")]
+    pub async fn connect_async(&self) -> Result<AsyncPilotOne<tokio::net::TcpStream>> {
+        use std::io::{Error, ErrorKind};
+
+        let stream = tokio::time::timeout(self.timeout, tokio::net::TcpStream::connect(self.addr))
+            .await
+            .map_err(|_| Error::new(ErrorKind::TimedOut, "Connection timed out"))??;
+        stream.set_nodelay(true)?;
+        self.set_keepalive(&SockRef::from(&stream))?;
+        Ok(AsyncPilotOne::new(stream).with_timeouts(self.timeouts()))
+    }
+
+    const fn timeouts(&self) -> Timeouts {
+        Timeouts {
+            exchange: self.timeout,
+        }
+    }
+
+    fn set_keepalive(&self, socket: &SockRef<'_>) -> Result<()> {
+        socket.set_tcp_keepalive(&TcpKeepalive::new().with_time(self.keepalive))
+    }
+}
+
+impl From<IpAddr> for Connector {
+    fn from(from: IpAddr) -> Self {
+        Self::new(from)
+    }
+}
+
+impl From<SocketAddr> for Connector {
+    fn from(from: SocketAddr) -> Self {
+        Self::with_addr(from)
+    }
+}
+
+/// Connect to the Com.G@te at `ip` with the default settings.
+pub fn connect(ip: IpAddr) -> Result<PilotOne<TcpStream>> {
+    Connector::new(ip).connect()
+}
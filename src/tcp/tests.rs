@@ -0,0 +1,53 @@
+use std::{
+    io::{Read, Write},
+    net::{Ipv4Addr, TcpListener},
+    thread,
+};
+
+use super::*;
+use crate::Temperature;
+
+#[test]
+fn default_port() {
+    let connector = Connector::new(Ipv4Addr::LOCALHOST.into());
+    assert_eq!(connector.addr().port(), 8101);
+}
+
+#[test]
+fn connect_and_query() {
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+    let addr = listener.local_addr().unwrap();
+    let device = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut request = [0; 10];
+        stream.read_exact(&mut request).unwrap();
+        stream.write_all(b"{S0109C4\r\n").unwrap();
+        request
+    });
+    let mut client = Connector::with_addr(addr).connect().unwrap();
+    let temp = client.get_internal_temp().unwrap();
+    assert_eq!(temp, Temperature::from_centi_celsius(2500));
+    assert_eq!(&device.join().unwrap(), b"{M01****\r\n");
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn connect_async_and_query() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind((Ipv4Addr::LOCALHOST, 0))
+        .await
+        .unwrap();
+    let addr = listener.local_addr().unwrap();
+    let device = tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut request = [0; 10];
+        stream.read_exact(&mut request).await.unwrap();
+        stream.write_all(b"{S0109C4\r\n").await.unwrap();
+        request
+    });
+    let mut client = Connector::with_addr(addr).connect_async().await.unwrap();
+    let temp = client.get_internal_temp().await.unwrap();
+    assert_eq!(temp, Temperature::from_centi_celsius(2500));
+    assert_eq!(&device.await.unwrap(), b"{M01****\r\n");
+}
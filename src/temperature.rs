@@ -0,0 +1,73 @@
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::centi;
+
+/// Temperature as transmitted by the Pilot ONE.
+///
+/// The device encodes temperatures as signed 16-bit
+/// values with a resolution of 0.01 °C.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Temperature(i16);
+
+impl Temperature {
+    /// Smallest temperature that can be encoded (-327.68 °C)
+    pub const MIN: Self = Self(i16::MIN);
+
+    /// Largest temperature that can be encoded (327.67 °C)
+    pub const MAX: Self = Self(i16::MAX);
+
+    #[must_use]
+    pub const fn from_centi_celsius(x: i16) -> Self {
+        Self(x)
+    }
+
+    #[must_use]
+    pub const fn centi_celsius(self) -> i16 {
+        self.0
+    }
+
+    /// Returns `None` if the value can't be represented.
+    #[must_use]
+    pub fn from_celsius(x: f64) -> Option<Self> {
+        centi::from_f64(x).map(Self)
+    }
+
+    #[must_use]
+    pub fn celsius(self) -> f64 {
+        centi::to_f64(self.0)
+    }
+
+    #[must_use]
+    pub const fn from_data(x: u16) -> Self {
+        Self(i16::from_be_bytes(x.to_be_bytes()))
+    }
+
+    #[must_use]
+    pub const fn to_data(self) -> u16 {
+        u16::from_be_bytes(self.0.to_be_bytes())
+    }
+}
+
+impl From<u16> for Temperature {
+    fn from(from: u16) -> Self {
+        Self::from_data(from)
+    }
+}
+
+impl From<Temperature> for u16 {
+    fn from(from: Temperature) -> Self {
+        from.to_data()
+    }
+}
+
+/// Formats the temperature in °C, e.g. `-2.50 °C`.
+impl fmt::Display for Temperature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        centi::fmt(self.0, "°C", f)
+    }
+}
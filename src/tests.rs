@@ -94,6 +94,39 @@ fn test_to_upper_hex() {
     assert_eq!(to_upper_hex(15) as char, 'F');
 }
 
+#[test]
+fn to_upper_hex_all_byte_values() {
+    for x in 0..=u8::MAX {
+        let digit = to_upper_hex(x);
+        assert_eq!(digit, to_upper_hex(x & 0x0F));
+        assert_eq!(char::from(digit).to_digit(16), Some(u32::from(x & 0x0F)));
+        assert!(!digit.is_ascii_lowercase());
+
+        let [hi, lo] = to_upper_hex_pair(x);
+        let s = [hi, lo];
+        let s = core::str::from_utf8(&s).unwrap();
+        assert_eq!(u8::from_str_radix(s, 16), Ok(x));
+    }
+}
+
+#[test]
+fn encode_all_byte_values() {
+    for x in 0..=u8::MAX {
+        for sender in [Sender::Master, Sender::Slave] {
+            for data in [None, Some(u16::from(x)), Some(u16::from_be_bytes([x, !x]))] {
+                let cmd = Command {
+                    sender,
+                    address: x,
+                    data,
+                };
+                let bytes = cmd.into_bytes();
+                let s = core::str::from_utf8(&bytes).unwrap();
+                assert_eq!(Command::from_str(s), Ok(cmd));
+            }
+        }
+    }
+}
+
 #[test]
 fn encode_address() {
     let data = None;
@@ -150,7 +183,7 @@ fn encode_data() {
     let cmd = Command {
         sender,
         address,
-        data: Some(core::u16::MAX),
+        data: Some(u16::MAX),
     }
     .into_bytes();
     assert_eq!(&cmd[4..8], b"FFFF");
@@ -194,19 +227,48 @@ fn encode_complete_command() {
     assert_eq!(cmd, *b"{S190001\r\n");
 }
 
+#[test]
+fn encode_at_compile_time() {
+    const READ_INTERNAL_TEMP: [u8; 10] = Command::query(Address::InternalTemp).into_bytes();
+    const SET_SETPOINT: [u8; 10] = Command::set(Address::SetpointTempControl, 0x05E8).into_bytes();
+    assert_eq!(READ_INTERNAL_TEMP, *b"{M01****\r\n");
+    assert_eq!(SET_SETPOINT, *b"{M0005E8\r\n");
+}
+
 #[test]
 fn encode_address_enum() {
     use self::Address::*;
     let expected = &[
         (SetpointTempControl, 0x00),
         (InternalTemp, 0x01),
+        (PumpPressure, 0x03),
+        (CurrentPower, 0x04),
         (ErrorReport, 0x05),
         (WarningMessage, 0x06),
+        (ProcessTemp, 0x07),
         (SetProcessTemp, 0x09),
+        (Status, 0x0A),
         (TempControlMode, 0x13),
         (TempControl, 0x14),
+        (Circulation, 0x16),
         (OperationLock, 0x17),
+        (Degassing, 0x18),
         (ProcessTempActualSettingMode, 0x19),
+        (UpperAlarmLimit, 0x1B),
+        (LowerAlarmLimit, 0x1C),
+        (UpperDeltaLimit, 0x1D),
+        (LowerDeltaLimit, 0x1E),
+        (PumpSpeed, 0x26),
+        (PumpSpeedSetpoint, 0x48),
+        (ComGateDigitalInputs, 0x5A),
+        (ComGateDigitalOutputs, 0x5B),
+        (ComGateAnalogInput, 0x5C),
+        (ComGateAnalogOutput, 0x5D),
+        (EcsStandby, 0x5E),
+        (DeviceType, 0x6A),
+        (FirmwareVersion, 0x6B),
+        (SerialNumberHigh, 0x6C),
+        (SerialNumberLow, 0x6D),
     ];
     for (addr, nr) in expected {
         assert_eq!(*addr as u8, *nr);
@@ -221,16 +283,644 @@ fn decode_address_enum() {
     let expected = &[
         (SetpointTempControl, 0x00),
         (InternalTemp, 0x01),
+        (PumpPressure, 0x03),
+        (CurrentPower, 0x04),
         (ErrorReport, 0x05),
         (WarningMessage, 0x06),
+        (ProcessTemp, 0x07),
         (SetProcessTemp, 0x09),
+        (Status, 0x0A),
         (TempControlMode, 0x13),
         (TempControl, 0x14),
+        (Circulation, 0x16),
         (OperationLock, 0x17),
+        (Degassing, 0x18),
         (ProcessTempActualSettingMode, 0x19),
+        (UpperAlarmLimit, 0x1B),
+        (LowerAlarmLimit, 0x1C),
+        (UpperDeltaLimit, 0x1D),
+        (LowerDeltaLimit, 0x1E),
+        (PumpSpeed, 0x26),
+        (PumpSpeedSetpoint, 0x48),
+        (ComGateDigitalInputs, 0x5A),
+        (ComGateDigitalOutputs, 0x5B),
+        (ComGateAnalogInput, 0x5C),
+        (ComGateAnalogOutput, 0x5D),
+        (EcsStandby, 0x5E),
+        (DeviceType, 0x6A),
+        (FirmwareVersion, 0x6B),
+        (SerialNumberHigh, 0x6C),
+        (SerialNumberLow, 0x6D),
     ];
     for (addr, nr) in expected {
         assert_eq!(Address::from_u8(*nr).unwrap(), *addr);
     }
     assert!(Address::from_u8(255).is_none());
 }
+
+#[test]
+fn query_and_set_commands() {
+    let cmd = Command::query(Address::InternalTemp);
+    assert_eq!(cmd.into_bytes(), *b"{M01****\r\n");
+    let cmd = Command::set(Address::SetpointTempControl, 0x05E8);
+    assert_eq!(cmd.into_bytes(), *b"{M0005E8\r\n");
+}
+
+#[test]
+fn temperature_from_data() {
+    assert_eq!(Temperature::from(0x09C4).centi_celsius(), 2500);
+    assert_eq!(Temperature::from(0xFF38).centi_celsius(), -200);
+    assert_eq!(u16::from(Temperature::from_centi_celsius(-200)), 0xFF38);
+    assert_eq!(Temperature::from(0x8000), Temperature::MIN);
+}
+
+#[test]
+fn temperature_from_celsius() {
+    let t = Temperature::from_celsius(36.5).unwrap();
+    assert_eq!(t.centi_celsius(), 3650);
+    let t = Temperature::from_celsius(-0.014).unwrap();
+    assert_eq!(t.centi_celsius(), -1);
+    assert!((t.celsius() + 0.01).abs() < f64::EPSILON);
+    assert!(Temperature::from_celsius(327.67).is_some());
+    assert!(Temperature::from_celsius(400.0).is_none());
+    assert!(Temperature::from_celsius(f64::NAN).is_none());
+}
+
+#[test]
+fn firmware_version_from_data() {
+    let version = FirmwareVersion::from(0x020A);
+    assert_eq!(version, FirmwareVersion::new(2, 10));
+    assert_eq!(u16::from(version), 0x020A);
+    assert!(FirmwareVersion::new(1, 99) < version);
+    assert_eq!(DeviceInfo::serial_number_from_data(0x0001, 0xE240), 123_456);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn display_device_info() {
+    let info = DeviceInfo {
+        device_type: 0x0123,
+        firmware_version: FirmwareVersion::new(2, 5),
+        serial_number: 123_456,
+    };
+    assert_eq!(info.firmware_version.to_string(), "2.05");
+    assert_eq!(
+        info.to_string(),
+        "type 0x0123, firmware 2.05, serial number 123456"
+    );
+}
+
+#[test]
+fn pump_speed_from_data() {
+    assert_eq!(PumpSpeed::from(0x05DC).rpm(), 1500);
+    assert_eq!(u16::from(PumpSpeed::from_rpm(3000)), 0x0BB8);
+}
+
+#[test]
+fn pressure_from_data() {
+    let p = Pressure::from(0x00FA);
+    assert_eq!(p.millibar(), 250);
+    assert!((p.bar() - 0.25).abs() < f64::EPSILON);
+    assert_eq!(Pressure::from(0xFFFF).millibar(), -1);
+    assert_eq!(u16::from(Pressure::from_millibar(-1)), 0xFFFF);
+}
+
+#[test]
+fn power_from_percent() {
+    let p = Power::from_percent(-12.5).unwrap();
+    assert_eq!(p.centi_percent(), -1250);
+    assert_eq!(p.to_data(), 0xFB1E);
+    assert!((Power::from(0x2710).percent() - 100.0).abs() < f64::EPSILON);
+    assert!(Power::from_percent(400.0).is_none());
+}
+
+#[test]
+fn decode_quantities() {
+    assert_eq!(
+        Response::from_data(Address::PumpSpeed, 1500),
+        Ok(Response::PumpSpeed(PumpSpeed::from_rpm(1500)))
+    );
+    assert_eq!(
+        Response::from_data(Address::CurrentPower, 0xFB1E),
+        Ok(Response::CurrentPower(Power::from_centi_percent(-1250)))
+    );
+    assert_eq!(
+        Response::from_data(Address::PumpPressure, 250)
+            .unwrap()
+            .data(),
+        250
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn display_quantities() {
+    assert_eq!(PumpSpeed::from_rpm(1500).to_string(), "1500 rpm");
+    assert_eq!(Pressure::from_millibar(-20).to_string(), "-20 mbar");
+    assert_eq!(Power::from_centi_percent(-5).to_string(), "-0.05 %");
+    let reply = Command {
+        sender: Slave,
+        address: Address::PumpSpeed.into(),
+        data: Some(1500),
+    };
+    assert_eq!(format!("{reply:#}"), "Slave PumpSpeed: 1500 rpm");
+}
+
+#[test]
+fn unknown_address_is_never_known() {
+    assert_eq!(UnknownAddress::new(0x01), None);
+    assert_eq!(
+        UnknownAddress::new(0x7F).map(UnknownAddress::get),
+        Some(0x7F)
+    );
+    assert_eq!(
+        CommandAddress::from(0x01),
+        CommandAddress::Known(Address::InternalTemp)
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_json_command_address() {
+    let addr = CommandAddress::from(0x7F);
+    assert_eq!(serde_json::to_string(&addr).unwrap(), "127");
+    assert_eq!(serde_json::from_str::<CommandAddress>("127").unwrap(), addr);
+    assert_eq!(
+        serde_json::from_str::<CommandAddress>("1").unwrap(),
+        CommandAddress::Known(Address::InternalTemp)
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_json_roundtrip() {
+    let cmd = Command::set(Address::SetpointTempControl, 0x05E8);
+    let json = serde_json::to_string(&cmd).unwrap();
+    assert_eq!(json, r#"{"sender":"Master","address":0,"data":1512}"#);
+    assert_eq!(serde_json::from_str::<Command>(&json).unwrap(), cmd);
+
+    let json = serde_json::to_string(&Address::InternalTemp).unwrap();
+    assert_eq!(json, r#""InternalTemp""#);
+    assert_eq!(
+        serde_json::from_str::<Address>(&json).unwrap(),
+        Address::InternalTemp
+    );
+
+    let json = serde_json::to_string(&ParseError::Sender {
+        offset: 1,
+        byte: b'X',
+    })
+    .unwrap();
+    assert_eq!(
+        serde_json::from_str::<ParseError>(&json).unwrap(),
+        ParseError::Sender {
+            offset: 1,
+            byte: b'X'
+        }
+    );
+}
+
+#[test]
+fn decode_error_code() {
+    assert_eq!(ErrorCode::from_u16(0), ErrorCode::NoError);
+    assert!(!ErrorCode::NoError.is_error());
+    assert_eq!(ErrorCode::from(2), ErrorCode::LowLevel);
+    assert!(ErrorCode::LowLevel.is_error());
+    assert_eq!(ErrorCode::from(0x1234), ErrorCode::Unknown(0x1234));
+    for code in 0..=10 {
+        assert_eq!(ErrorCode::from_u16(code).code(), code);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn display_error_code() {
+    assert_eq!(
+        ErrorCode::Overtemperature.to_string(),
+        "Over-temperature protection triggered (error 1)"
+    );
+    assert_eq!(
+        ErrorCode::Unknown(42).to_string(),
+        "Unknown error (error 42)"
+    );
+}
+
+#[test]
+fn decode_warning_code() {
+    assert_eq!(WarningCode::from_u16(0), WarningCode::NoWarning);
+    assert!(!WarningCode::NoWarning.is_warning());
+    assert_eq!(WarningCode::from(1), WarningCode::HighTemperature);
+    assert_eq!(WarningCode::from(999), WarningCode::Unknown(999));
+    for code in 0..=10 {
+        assert_eq!(WarningCode::from_u16(code).code(), code);
+    }
+}
+
+#[test]
+fn warning_severity() {
+    assert_eq!(WarningCode::MaintenanceDue.severity(), Severity::Info);
+    assert_eq!(WarningCode::LowLevel.severity(), Severity::Warning);
+    assert_eq!(WarningCode::WatchdogTimeout.severity(), Severity::Fault);
+    assert_eq!(WarningCode::Unknown(999).severity(), Severity::Fault);
+    assert!(Severity::Info < Severity::Warning);
+    assert!(Severity::Warning < Severity::Fault);
+}
+
+#[test]
+fn temp_control_mode_data() {
+    assert_eq!(
+        TempControlMode::try_from(0).unwrap(),
+        TempControlMode::Internal
+    );
+    assert_eq!(
+        TempControlMode::try_from(1).unwrap(),
+        TempControlMode::Process
+    );
+    assert_eq!(TempControlMode::try_from(2), Err(InvalidValue(2)));
+    let cmd = Command::set_temp_control_mode(TempControlMode::Process);
+    assert_eq!(cmd.into_bytes(), *b"{M130001\r\n");
+}
+
+#[test]
+fn on_off_data() {
+    assert_eq!(OnOff::try_from(0).unwrap(), OnOff::Off);
+    assert_eq!(OnOff::try_from(1).unwrap(), OnOff::On);
+    assert_eq!(OnOff::try_from(2), Err(InvalidValue(2)));
+    assert_eq!(OnOff::from(true), OnOff::On);
+    assert!(!bool::from(OnOff::Off));
+    let cmd = Command::set_switch(Address::TempControl, OnOff::On);
+    assert_eq!(cmd.into_bytes(), *b"{M140001\r\n");
+}
+
+#[test]
+fn decode_response() {
+    let cmd = Command::from_str("{S0109C4\r\n").unwrap();
+    let res = Response::decode(&cmd).unwrap();
+    assert_eq!(
+        res,
+        Response::InternalTemp(Temperature::from_centi_celsius(2500))
+    );
+    assert_eq!(res.address(), Address::InternalTemp);
+    assert_eq!(res.data(), 0x09C4);
+
+    let cmd = Command::from_str("{S140001\r\n").unwrap();
+    assert_eq!(
+        Response::try_from(cmd),
+        Ok(Response::TempControl(OnOff::On))
+    );
+
+    let cmd = Command::from_str("{S140002\r\n").unwrap();
+    assert_eq!(
+        Response::try_from(cmd),
+        Err(DecodeError::InvalidValue(InvalidValue(2)))
+    );
+
+    let cmd = Command::from_str("{S14****\r\n").unwrap();
+    assert_eq!(Response::try_from(cmd), Err(DecodeError::MissingData));
+
+    let cmd = Command::from_str("{S7F0001\r\n").unwrap();
+    assert_eq!(Response::try_from(cmd), Err(DecodeError::Address(0x7F)));
+}
+
+#[test]
+fn operation_lock_data() {
+    assert_eq!(
+        crate::OperationLock::try_from(1).unwrap(),
+        crate::OperationLock::Locked
+    );
+    assert_eq!(crate::OperationLock::try_from(3), Err(InvalidValue(3)));
+    let cmd = Command::set_operation_lock(crate::OperationLock::Locked);
+    assert_eq!(cmd.into_bytes(), *b"{M170001\r\n");
+}
+
+#[test]
+fn verify_exchange() {
+    let exchange = Exchange::new(Command::query(Address::InternalTemp));
+    let reply = Command::from_str("{S0109C4\r\n").unwrap();
+    assert!(exchange.matches(&reply));
+    assert_eq!(exchange.verify(reply), Ok(reply));
+
+    let wrong_address = Command::from_str("{S0009C4\r\n").unwrap();
+    assert_eq!(
+        exchange.verify(wrong_address),
+        Err(ResponseMismatch {
+            request: exchange.request(),
+            response: wrong_address,
+        })
+    );
+
+    let wrong_sender = Command::from_str("{M0109C4\r\n").unwrap();
+    assert!(!exchange.matches(&wrong_sender));
+}
+
+#[test]
+fn set_outcome_from_echo() {
+    assert_eq!(
+        SetOutcome::from_echo(0x05E8, Some(0x05E8)),
+        SetOutcome::Accepted
+    );
+    assert_eq!(
+        SetOutcome::from_echo(0x05E8, Some(0x04B0)),
+        SetOutcome::Clamped { actual: 0x04B0 }
+    );
+    assert_eq!(SetOutcome::from_echo(0x05E8, None), SetOutcome::Rejected);
+    assert!(SetOutcome::Accepted.is_accepted());
+    assert!(!SetOutcome::Rejected.is_accepted());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn display_temperature() {
+    let t = |x| Temperature::from_centi_celsius(x).to_string();
+    assert_eq!(t(1512), "15.12 °C");
+    assert_eq!(t(-250), "-2.50 °C");
+    assert_eq!(t(-5), "-0.05 °C");
+    assert_eq!(t(i16::MIN), "-327.68 °C");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn display_command() {
+    let cmd = Command::set(Address::SetProcessTemp, 0x05E8);
+    assert_eq!(cmd.to_string(), "{M0905E8");
+    assert_eq!(format!("{cmd:#}"), "Master SetProcessTemp: 15.12 °C");
+
+    let cmd = Command::query(Address::InternalTemp);
+    assert_eq!(cmd.to_string(), "{M01****");
+    assert_eq!(format!("{cmd:#}"), "Master InternalTemp: ?");
+
+    let cmd = Command::from_str("{S050001\r\n").unwrap();
+    assert_eq!(
+        format!("{cmd:#}"),
+        "Slave ErrorReport: Over-temperature protection triggered (1)"
+    );
+
+    let cmd = Command::from_str("{S140001\r\n").unwrap();
+    assert_eq!(format!("{cmd:#}"), "Slave TempControl: On");
+
+    let cmd = Command::from_str("{S140007\r\n").unwrap();
+    assert_eq!(format!("{cmd:#}"), "Slave TempControl: 0x0007");
+
+    let cmd = Command::from_str("{S7F0001\r\n").unwrap();
+    assert_eq!(format!("{cmd:#}"), "Slave 0x7F: 0x0001");
+}
+
+#[test]
+fn from_str_is_case_sensitive() {
+    assert!(Command::from_str("{M09ab00\r\n").is_err());
+    assert!(Command::from_str("{m09AB00\r\n").is_err());
+    assert!(Command::from_str("{M+10000\r\n").is_err());
+}
+
+#[test]
+fn from_str_lenient() {
+    let expected = Command::set(Address::SetProcessTemp, 0x05E8);
+    for s in [
+        "{M0905E8\r\n",
+        "{m0905e8\r\n",
+        "{M0905E8",
+        "{M0905E8\n",
+        "{M0905E8\r",
+        "  {M0905E8\r\n  ",
+        "\n{M0905e8 \t",
+    ] {
+        assert_eq!(Command::from_str_lenient(s), Ok(expected), "{s:?}");
+    }
+    assert_eq!(
+        Command::from_str_lenient("{M0905E"),
+        Err(ParseError::MessageLength { len: 7 })
+    );
+    assert_eq!(
+        Command::from_str_lenient("{x0905E8"),
+        Err(ParseError::Sender {
+            offset: 1,
+            byte: b'x'
+        })
+    );
+}
+
+#[test]
+fn parse_with_options() {
+    let options = ParseOptions {
+        ignore_case: true,
+        ..ParseOptions::strict()
+    };
+    assert!(Command::parse_with("{s0905e8\r\n", options).is_ok());
+    assert!(Command::parse_with("{s0905e8", options).is_err());
+
+    let options = ParseOptions {
+        optional_line_ending: true,
+        ..ParseOptions::strict()
+    };
+    assert!(Command::parse_with("{S0905E8", options).is_ok());
+    assert!(Command::parse_with("{S0905e8", options).is_err());
+    assert!(Command::parse_with(" {S0905E8", options).is_err());
+}
+
+#[test]
+fn from_str_with_bad_delimiters() {
+    assert_eq!(
+        Command::from_str("XM31****??"),
+        Err(ParseError::StartByte { byte: b'X' })
+    );
+    assert_eq!(
+        Command::from_str("{M31****??"),
+        Err(ParseError::LineEnding { offset: 8 })
+    );
+    assert_eq!(
+        Command::from_str("{M31****\n\r"),
+        Err(ParseError::LineEnding { offset: 8 })
+    );
+    assert_eq!(
+        Command::from_str_lenient("XM31****"),
+        Err(ParseError::StartByte { byte: b'X' })
+    );
+    let options = ParseOptions {
+        ignore_delimiters: true,
+        ..ParseOptions::strict()
+    };
+    let cmd = Command::parse_with("XM31****??", options).unwrap();
+    assert_eq!(cmd.address, 0x31);
+}
+
+#[test]
+fn parse_error_context() {
+    let err = Command::from_str("{M3G****\r\n").unwrap_err();
+    assert_eq!(
+        err,
+        ParseError::Address {
+            offset: 3,
+            byte: b'G'
+        }
+    );
+    assert_eq!(err.offset(), Some(3));
+    assert_eq!(err.byte(), Some(b'G'));
+
+    let err = Command::from_str("{M3100+1\r\n").unwrap_err();
+    assert_eq!(err.offset(), Some(6));
+    assert_eq!(err.byte(), Some(b'+'));
+
+    let err = Command::from_str("{M31****\r\n\n").unwrap_err();
+    assert_eq!(err, ParseError::MessageLength { len: 11 });
+    assert_eq!(err.offset(), None);
+    assert_eq!(err.byte(), None);
+
+    assert_eq!(
+        Command::from_str("{M31**\u{e4}\r\n"),
+        Err(ParseError::NonAsciiStr { offset: 6 })
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn display_parse_error() {
+    let err = ParseError::Sender {
+        offset: 1,
+        byte: b'X',
+    };
+    assert_eq!(err.to_string(), "Invalid sender 'X' at offset 1");
+    let err = ParseError::StartByte { byte: b'\n' };
+    assert_eq!(err.to_string(), "Missing start character, found 0x0A");
+    let err = ParseError::Checksum { expected: 0x0E };
+    assert_eq!(err.to_string(), "Invalid checksum, expected 0E");
+}
+
+#[test]
+fn typed_command() {
+    let cmd = TypedCommand::from_str("{S0109C4\r\n").unwrap();
+    assert_eq!(cmd.address, CommandAddress::Known(Address::InternalTemp));
+    assert_eq!(cmd.into_bytes(), *b"{S0109C4\r\n");
+
+    let cmd = TypedCommand::from_str("{S7F0001\r\n").unwrap();
+    assert_eq!(
+        cmd.address,
+        CommandAddress::Unknown(UnknownAddress::new(0x7F).unwrap())
+    );
+    assert_eq!(Command::from(cmd).address, 0x7F);
+
+    let cmd = Command::query(Address::TempControl);
+    assert_eq!(
+        cmd.typed_address(),
+        CommandAddress::Known(Address::TempControl)
+    );
+    assert_eq!(TypedCommand::from(cmd).sender, Sender::Master);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn write_to_and_read_from() {
+    let mut buf = Vec::new();
+    Command::query(Address::InternalTemp)
+        .write_to(&mut buf)
+        .unwrap();
+    Command::set(Address::SetProcessTemp, 0x05E8)
+        .write_to(&mut buf)
+        .unwrap();
+    assert_eq!(buf, b"{M01****\r\n{M0905E8\r\n");
+
+    let mut reader = &b"\0*{S0109C4\r\n{S0905E8\r\n{S01"[..];
+    let cmd = Command::read_from(&mut reader).unwrap();
+    assert_eq!(cmd.data, Some(0x09C4));
+    let cmd = Command::read_from(&mut reader).unwrap();
+    assert_eq!(cmd.address, 0x09);
+    let err = Command::read_from(&mut reader).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+}
+
+/// Fixed-size [`fmt::Write`] sink that works without `std`.
+struct Buf<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> Buf<N> {
+    const fn new() -> Self {
+        Self {
+            bytes: [0; N],
+            len: 0,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+    }
+}
+
+impl<const N: usize> fmt::Write for Buf<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let dst = self
+            .bytes
+            .get_mut(self.len..self.len + s.len())
+            .ok_or(fmt::Error)?;
+        dst.copy_from_slice(s.as_bytes());
+        self.len += s.len();
+        Ok(())
+    }
+}
+
+#[test]
+fn encode_to_fmt() {
+    let mut buf = Buf::<16>::new();
+    let cmd = Command::set(Address::SetProcessTemp, 0x05E8);
+    cmd.encode_to_fmt(&mut buf).unwrap();
+    assert_eq!(&buf.bytes[..buf.len], b"{M0905E8\r\n");
+    assert!(cmd.encode_to_fmt(&mut buf).is_err());
+}
+
+#[test]
+fn display_errors_without_std() {
+    use fmt::Write as _;
+
+    let mut buf = Buf::<64>::new();
+    write!(buf, "{}", ValidationError::ReadOnly(Address::InternalTemp)).unwrap();
+    assert_eq!(buf.as_str(), "InternalTemp is read-only");
+    let mut buf = Buf::<64>::new();
+    write!(buf, "{}", DecodeError::Address(0x7F)).unwrap();
+    assert_eq!(buf.as_str(), "Unknown command address: 0x7F");
+    let mut buf = Buf::<64>::new();
+    write!(buf, "{}", InvalidValue(2)).unwrap();
+    assert_eq!(buf.as_str(), "Invalid value: 0x0002");
+}
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_round_trip {
+    use super::*;
+    use arbitrary::{Arbitrary, Unstructured};
+
+    /// Deterministic pseudo-random input for [`Unstructured`].
+    fn random_bytes(seed: u64) -> [u8; 1024] {
+        let mut state = seed | 1;
+        let mut bytes = [0; 1024];
+        for b in &mut bytes {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            *b = state.to_le_bytes()[0];
+        }
+        bytes
+    }
+
+    #[test]
+    fn encode_parse_identity() {
+        for seed in 0..100 {
+            let bytes = random_bytes(seed);
+            let mut u = Unstructured::new(&bytes);
+            while !u.is_empty() {
+                let cmd = Command::arbitrary(&mut u).unwrap();
+                let frame = cmd.into_bytes();
+                let s = core::str::from_utf8(&frame).unwrap();
+                assert_eq!(s.parse::<Command>(), Ok(cmd));
+            }
+        }
+    }
+
+    #[test]
+    fn address_round_trip() {
+        for seed in 0..100 {
+            let bytes = random_bytes(seed);
+            let mut u = Unstructured::new(&bytes);
+            let address = Address::arbitrary(&mut u).unwrap();
+            assert_eq!(Address::from_u8(address.into()), Some(address));
+        }
+    }
+}
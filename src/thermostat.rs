@@ -0,0 +1,107 @@
+use std::io::{Error, ErrorKind, Read, Result, Write};
+
+use crate::{Address, OnOff, PilotOne, TempControlMode, Temperature};
+
+#[cfg(test)]
+mod tests;
+
+/// High-level access to a thermostat.
+///
+/// All temperatures are in °C.
+#[derive(Debug)]
+pub struct Thermostat<T> {
+    client: PilotOne<T>,
+}
+
+impl<T> Thermostat<T> {
+    #[must_use]
+    pub const fn new(transport: T) -> Self {
+        Self::with_client(PilotOne::new(transport))
+    }
+
+    #[must_use]
+    pub const fn with_client(client: PilotOne<T>) -> Self {
+        Self { client }
+    }
+
+    #[must_use]
+    pub const fn client(&self) -> &PilotOne<T> {
+        &self.client
+    }
+
+    pub fn client_mut(&mut self) -> &mut PilotOne<T> {
+        &mut self.client
+    }
+
+    #[must_use]
+    pub fn into_client(self) -> PilotOne<T> {
+        self.client
+    }
+}
+
+impl<T> Thermostat<T>
+where
+    T: Read + Write,
+{
+    pub fn setpoint_celsius(&mut self) -> Result<f64> {
+        self.client.get_setpoint().map(Temperature::celsius)
+    }
+
+    /// Set the setpoint and return the value accepted by the device.
+    pub fn set_setpoint_celsius(&mut self, celsius: f64) -> Result<f64> {
+        let temp = Temperature::from_celsius(celsius)
+            .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "Temperature out of range"))?;
+        self.client.set_setpoint(temp).map(Temperature::celsius)
+    }
+
+    pub fn internal_temperature(&mut self) -> Result<f64> {
+        self.client.get_internal_temp().map(Temperature::celsius)
+    }
+
+    pub fn process_temperature(&mut self) -> Result<f64> {
+        self.client
+            .query(Address::ProcessTemp)
+            .map(|x| Temperature::from(x).celsius())
+    }
+
+    /// Start temperature control.
+    ///
+    /// Fails if the device does not confirm the start.
+    pub fn start(&mut self) -> Result<()> {
+        let state = self.client.set_switch(Address::TempControl, OnOff::On)?;
+        confirm(state, OnOff::On, "Temperature control not started")
+    }
+
+    /// Stop temperature control.
+    ///
+    /// Fails if the device does not confirm the stop.
+    pub fn stop(&mut self) -> Result<()> {
+        let state = self.client.set_switch(Address::TempControl, OnOff::Off)?;
+        confirm(state, OnOff::Off, "Temperature control not stopped")
+    }
+
+    /// Whether temperature control is active.
+    pub fn is_running(&mut self) -> Result<bool> {
+        self.client
+            .get_switch(Address::TempControl)
+            .map(OnOff::is_on)
+    }
+
+    pub fn control_mode(&mut self) -> Result<TempControlMode> {
+        self.client.get_temp_control_mode()
+    }
+
+    /// Fails if the device does not confirm `mode`.
+    pub fn set_control_mode(&mut self, mode: TempControlMode) -> Result<()> {
+        let accepted = self.client.set_temp_control_mode(mode)?;
+        confirm(accepted, mode, "Temperature control mode not confirmed")
+    }
+}
+
+fn confirm<V: PartialEq + Copy>(actual: V, expected: V, msg: &'static str) -> Result<()> {
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(Error::new(ErrorKind::InvalidData, msg))
+    }
+}
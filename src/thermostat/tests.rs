@@ -0,0 +1,59 @@
+use super::*;
+use crate::mock::Mock;
+
+#[test]
+fn temperatures() {
+    let mut thermostat = Thermostat::new(Mock::new(b"{S0109C4\r\n{S07FF38\r\n"));
+    assert!((thermostat.internal_temperature().unwrap() - 25.0).abs() < f64::EPSILON);
+    assert!((thermostat.process_temperature().unwrap() + 2.0).abs() < f64::EPSILON);
+    assert_eq!(
+        thermostat.client().get_ref().tx,
+        b"{M01****\r\n{M07****\r\n"
+    );
+}
+
+#[test]
+fn set_setpoint() {
+    let mut thermostat = Thermostat::new(Mock::new(b"{S000E42\r\n"));
+    let accepted = thermostat.set_setpoint_celsius(36.5).unwrap();
+    assert!((accepted - 36.5).abs() < f64::EPSILON);
+    assert_eq!(thermostat.client().get_ref().tx, b"{M000E42\r\n");
+
+    let err = thermostat.set_setpoint_celsius(1000.0).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+}
+
+#[test]
+fn start_and_stop() {
+    let mut thermostat = Thermostat::new(Mock::new(b"{S140001\r\n{S140000\r\n{S140000\r\n"));
+    thermostat.start().unwrap();
+    thermostat.stop().unwrap();
+    assert!(!thermostat.is_running().unwrap());
+    assert_eq!(
+        thermostat.into_client().into_inner().tx,
+        b"{M140001\r\n{M140000\r\n{M14****\r\n"
+    );
+}
+
+#[test]
+fn control_mode() {
+    let mut thermostat = Thermostat::new(Mock::new(b"{S130001\r\n"));
+    assert_eq!(thermostat.control_mode().unwrap(), TempControlMode::Process);
+}
+
+#[test]
+fn start_not_confirmed() {
+    let mut thermostat = Thermostat::new(Mock::new(b"{S140000\r\n"));
+    let err = thermostat.start().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn set_control_mode_not_confirmed() {
+    let mut thermostat = Thermostat::new(Mock::new(b"{S130000\r\n"));
+    let err = thermostat
+        .set_control_mode(TempControlMode::Process)
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert_eq!(thermostat.client().get_ref().tx, b"{M130001\r\n");
+}
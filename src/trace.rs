@@ -0,0 +1,371 @@
+//! Recording and replay of the traffic on a transport.
+//!
+//! A [`Recorder`] wraps a transport and records all frames
+//! that are sent and received. The resulting [`Trace`] can be
+//! stored in a line based log format, e.g.
+//!
+//! ```text
+//! 0.000000 TX {M01**** # Master InternalTemp: ?
+//! 0.001250 RX {S0109C4 # Slave InternalTemp: 25.00 °C
+//! ```
+//!
+//! Each line contains the time since the start of the recording
+//! in seconds, the direction and the raw frame without line ending.
+//! Everything behind ` # ` is a comment. Non-printable bytes, `\`
+//! and `#` are escaped as `\xNN`. Frames that don't end with `\r\n`
+//! are written completely and marked with a trailing `\`.
+//!
+//! A recorded session can be replayed against a device or the
+//! [`Simulator`](crate::simulator::Simulator) with [`replay`] or it can
+//! serve as fake device for the client side with [`Player`].
+
+use std::{
+    collections::VecDeque,
+    fmt::Write as _,
+    io::{BufRead, Error, ErrorKind, Read, Result, Write},
+    str,
+    time::{Duration, Instant},
+};
+
+use crate::Command;
+
+#[cfg(test)]
+mod tests;
+
+/// Direction of a frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Sent to the transport
+    Tx,
+    /// Received from the transport
+    Rx,
+}
+
+/// Single frame of a [`Trace`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    /// Time since the start of the recording
+    pub elapsed: Duration,
+    pub direction: Direction,
+    /// Raw bytes including the line ending
+    pub frame: Vec<u8>,
+}
+
+impl Record {
+    /// Decode the frame.
+    ///
+    /// Returns `None` if the frame is not a valid command.
+    #[must_use]
+    pub fn command(&self) -> Option<Command> {
+        let frame = str::from_utf8(&self.frame).ok()?;
+        let start = frame.find('{')?;
+        frame[start..].parse().ok()
+    }
+
+    fn line(&self) -> String {
+        let direction = match self.direction {
+            Direction::Tx => "TX",
+            Direction::Rx => "RX",
+        };
+        let mut line = format!(
+            "{}.{:06} {direction} ",
+            self.elapsed.as_secs(),
+            self.elapsed.subsec_micros()
+        );
+        let (frame, terminated) = match self.frame.strip_suffix(b"\r\n") {
+            Some(frame) => (frame, true),
+            None => (&self.frame[..], false),
+        };
+        for b in frame {
+            if b.is_ascii_graphic() && *b != b'\\' && *b != b'#' {
+                line.push(char::from(*b));
+            } else {
+                let _ = write!(line, "\\x{b:02X}");
+            }
+        }
+        if !terminated {
+            line.push('\\');
+        }
+        if let Some(cmd) = self.command() {
+            let _ = write!(line, " # {cmd:#}");
+        }
+        line
+    }
+
+    fn parse_line(line: &str) -> Option<Self> {
+        let line = line.split(" # ").next()?;
+        let mut parts = line.split(' ');
+        let elapsed = parts
+            .next()?
+            .parse()
+            .ok()
+            .and_then(|s| Duration::try_from_secs_f64(s).ok())?;
+        let direction = match parts.next()? {
+            "TX" => Direction::Tx,
+            "RX" => Direction::Rx,
+            _ => return None,
+        };
+        let escaped = parts.next().unwrap_or_default();
+        if parts.next().is_some() {
+            return None;
+        }
+        let (escaped, terminated) = match escaped.strip_suffix('\\') {
+            Some(escaped) => (escaped, false),
+            None => (escaped, true),
+        };
+        let mut frame = Vec::with_capacity(escaped.len() + 2);
+        let mut bytes = escaped.bytes();
+        while let Some(b) = bytes.next() {
+            if b == b'\\' {
+                let hex = [bytes.next()?, bytes.next()?, bytes.next()?];
+                let hex = str::from_utf8(&hex).ok()?.strip_prefix('x')?;
+                frame.push(u8::from_str_radix(hex, 16).ok()?);
+            } else {
+                frame.push(b);
+            }
+        }
+        if terminated {
+            frame.extend_from_slice(b"\r\n");
+        }
+        Some(Self {
+            elapsed,
+            direction,
+            frame,
+        })
+    }
+}
+
+/// Recorded session.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Trace {
+    pub records: Vec<Record>,
+}
+
+impl Trace {
+    /// Write the trace in the log format.
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> Result<()> {
+        for record in &self.records {
+            writeln!(writer, "{}", record.line())?;
+        }
+        Ok(())
+    }
+
+    /// Read a trace in the log format.
+    ///
+    /// Empty lines are skipped.
+    pub fn read_from<R: BufRead>(reader: R) -> Result<Self> {
+        let mut records = vec![];
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let record = Record::parse_line(&line).ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidData,
+                    format!("Invalid trace record in line {}", i + 1),
+                )
+            })?;
+            records.push(record);
+        }
+        Ok(Self { records })
+    }
+}
+
+/// Transport wrapper that records all frames.
+#[derive(Debug)]
+pub struct Recorder<T> {
+    transport: T,
+    start: Instant,
+    tx: Vec<u8>,
+    rx: Vec<u8>,
+    trace: Trace,
+}
+
+impl<T> Recorder<T> {
+    #[must_use]
+    pub fn new(transport: T) -> Self {
+        Self {
+            transport,
+            start: Instant::now(),
+            tx: vec![],
+            rx: vec![],
+            trace: Trace::default(),
+        }
+    }
+
+    #[must_use]
+    pub const fn get_ref(&self) -> &T {
+        &self.transport
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.transport
+    }
+
+    #[must_use]
+    pub const fn trace(&self) -> &Trace {
+        &self.trace
+    }
+
+    /// Return the transport and the recorded trace.
+    ///
+    /// Incomplete frames are dropped.
+    #[must_use]
+    pub fn into_parts(self) -> (T, Trace) {
+        (self.transport, self.trace)
+    }
+
+    fn record(&mut self, direction: Direction, bytes: &[u8]) {
+        for b in bytes {
+            let pending = match direction {
+                Direction::Tx => &mut self.tx,
+                Direction::Rx => &mut self.rx,
+            };
+            pending.push(*b);
+            if *b == b'\n' {
+                let frame = std::mem::take(pending);
+                self.trace.records.push(Record {
+                    elapsed: self.start.elapsed(),
+                    direction,
+                    frame,
+                });
+            }
+        }
+    }
+}
+
+impl<T: Read> Read for Recorder<T> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let len = self.transport.read(buf)?;
+        self.record(Direction::Rx, &buf[..len]);
+        Ok(len)
+    }
+}
+
+impl<T: Write> Write for Recorder<T> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let len = self.transport.write(buf)?;
+        self.record(Direction::Tx, &buf[..len]);
+        Ok(len)
+    }
+    fn flush(&mut self) -> Result<()> {
+        self.transport.flush()
+    }
+}
+
+/// Received frame that differs from the recording.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Divergence {
+    /// Index of the record in the trace
+    pub index: usize,
+    pub expected: Vec<u8>,
+    pub actual: Vec<u8>,
+}
+
+/// Send the recorded frames to `transport` and compare
+/// the received frames with the recording.
+pub fn replay<T: Read + Write>(trace: &Trace, transport: &mut T) -> Result<Vec<Divergence>> {
+    let mut divergences = vec![];
+    for (index, record) in trace.records.iter().enumerate() {
+        match record.direction {
+            Direction::Tx => {
+                transport.write_all(&record.frame)?;
+                transport.flush()?;
+            }
+            Direction::Rx => {
+                let actual = read_frame(transport)?;
+                if actual != record.frame {
+                    divergences.push(Divergence {
+                        index,
+                        expected: record.frame.clone(),
+                        actual,
+                    });
+                }
+            }
+        }
+    }
+    Ok(divergences)
+}
+
+fn read_frame<T: Read>(transport: &mut T) -> Result<Vec<u8>> {
+    let mut frame = vec![];
+    let mut byte = [0];
+    while frame.last() != Some(&b'\n') {
+        transport.read_exact(&mut byte)?;
+        frame.push(byte[0]);
+    }
+    Ok(frame)
+}
+
+/// Transport that answers with the recorded frames.
+///
+/// Written frames must match the recorded frames. The received
+/// frames that follow a sent frame in the trace become readable
+/// as soon as the sent frame has been written.
+#[derive(Debug)]
+pub struct Player {
+    records: VecDeque<Record>,
+    tx: Vec<u8>,
+    rx: VecDeque<u8>,
+}
+
+impl Player {
+    #[must_use]
+    pub fn new(trace: Trace) -> Self {
+        let mut player = Self {
+            records: trace.records.into(),
+            tx: vec![],
+            rx: VecDeque::new(),
+        };
+        player.release_rx();
+        player
+    }
+
+    /// Returns `true` if all recorded frames have been played.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.records.is_empty() && self.rx.is_empty()
+    }
+
+    fn release_rx(&mut self) {
+        while let Some(record) = self.records.front() {
+            if record.direction == Direction::Tx {
+                break;
+            }
+            self.rx.extend(&record.frame);
+            self.records.pop_front();
+        }
+    }
+}
+
+impl Read for Player {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        self.rx.read(buf)
+    }
+}
+
+impl Write for Player {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        for b in buf {
+            self.tx.push(*b);
+            if *b != b'\n' {
+                continue;
+            }
+            let frame = std::mem::take(&mut self.tx);
+            match self.records.pop_front() {
+                Some(record) if record.frame == frame => self.release_rx(),
+                _ => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        "Frame does not match the recording",
+                    ));
+                }
+            }
+        }
+        Ok(buf.len())
+    }
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+use super::*;
+use crate::{simulator::Simulator, Address, PilotOne, Temperature};
+
+fn record_session() -> Trace {
+    let mut client = PilotOne::new(Recorder::new(Simulator::new()));
+    client
+        .set_setpoint(Temperature::from_centi_celsius(3650))
+        .unwrap();
+    client.get_internal_temp().unwrap();
+    let (_, trace) = client.into_inner().into_parts();
+    trace
+}
+
+#[test]
+fn record_frames() {
+    let trace = record_session();
+    assert_eq!(trace.records.len(), 4);
+    assert_eq!(trace.records[0].direction, Direction::Tx);
+    assert_eq!(trace.records[0].frame, b"{M000E42\r\n");
+    assert_eq!(trace.records[1].direction, Direction::Rx);
+    assert_eq!(trace.records[3].frame, b"{S0107D0\r\n");
+    assert_eq!(
+        trace.records[3].command(),
+        Some(Command {
+            sender: crate::Sender::Slave,
+            address: Address::InternalTemp.into(),
+            data: Some(2000),
+        })
+    );
+}
+
+#[test]
+fn write_and_read_log() {
+    let trace = Trace {
+        records: vec![
+            Record {
+                elapsed: Duration::from_micros(1_250),
+                direction: Direction::Tx,
+                frame: b"{M01****\r\n".to_vec(),
+            },
+            Record {
+                elapsed: Duration::from_micros(2_000_500),
+                direction: Direction::Rx,
+                frame: b"\0#{S0109C4\r\n".to_vec(),
+            },
+        ],
+    };
+    let mut log = vec![];
+    trace.write_to(&mut log).unwrap();
+    let log = String::from_utf8(log).unwrap();
+    assert_eq!(
+        log,
+        "0.001250 TX {M01**** # Master InternalTemp: ?\n\
+         2.000500 RX \\x00\\x23{S0109C4 # Slave InternalTemp: 25.00 °C\n"
+    );
+    assert_eq!(Trace::read_from(log.as_bytes()).unwrap(), trace);
+}
+
+#[test]
+fn write_and_read_malformed_line_endings() {
+    let frames: [&[u8]; 4] = [b"{M01****\n", b"{S0109C4\r", b"{S0109C4\n\r\n", b""];
+    let trace = Trace {
+        records: frames
+            .iter()
+            .map(|frame| Record {
+                elapsed: Duration::ZERO,
+                direction: Direction::Rx,
+                frame: frame.to_vec(),
+            })
+            .collect(),
+    };
+    let mut log = vec![];
+    trace.write_to(&mut log).unwrap();
+    let log = String::from_utf8(log).unwrap();
+    assert_eq!(log.lines().next().unwrap(), r"0.000000 RX {M01****\x0A\");
+    assert_eq!(Trace::read_from(log.as_bytes()).unwrap(), trace);
+}
+
+#[test]
+fn read_invalid_log() {
+    let err = Trace::read_from(&b"0.0 TX {M01****\n\n0.1 XX {S01****\n"[..]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert!(err.to_string().contains("line 3"));
+}
+
+#[test]
+fn read_log_with_invalid_timestamp() {
+    for line in ["-1 TX {M01****\n", "NaN TX {M01****\n", "inf RX {S010001\n"] {
+        let err = Trace::read_from(line.as_bytes()).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert!(err.to_string().contains("line 1"));
+    }
+}
+
+#[test]
+fn replay_against_simulator() {
+    let trace = record_session();
+    assert!(replay(&trace, &mut Simulator::new()).unwrap().is_empty());
+
+    let mut sim = Simulator::new();
+    sim.set_register(Address::InternalTemp, 2500);
+    let divergences = replay(&trace, &mut sim).unwrap();
+    assert_eq!(divergences.len(), 1);
+    assert_eq!(divergences[0].index, 3);
+    assert_eq!(divergences[0].actual, b"{S0109C4\r\n");
+}
+
+#[test]
+fn play_recorded_session() {
+    let mut client = PilotOne::new(Player::new(record_session()));
+    let temp = Temperature::from_centi_celsius(3650);
+    assert_eq!(client.set_setpoint(temp).unwrap(), temp);
+    assert_eq!(
+        client.get_internal_temp().unwrap(),
+        Temperature::from_centi_celsius(2000)
+    );
+    assert!(client.get_ref().is_finished());
+
+    let mut client = PilotOne::new(Player::new(record_session()));
+    let err = client.get_internal_temp().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
@@ -0,0 +1,124 @@
+use core::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Address, Command, ParseError, Sender};
+
+/// Address of a [`TypedCommand`].
+///
+/// Serialized as the raw address, so that deserializing
+/// a known address always yields [`CommandAddress::Known`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "u8", into = "u8"))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CommandAddress {
+    Known(Address),
+    Unknown(UnknownAddress),
+}
+
+/// Address that is not (yet) supported by this crate
+///
+/// Never holds an address that has an [`Address`] variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct UnknownAddress(u8);
+
+impl UnknownAddress {
+    /// Returns `None` if `x` is a known [`Address`].
+    #[must_use]
+    pub const fn new(x: u8) -> Option<Self> {
+        if Address::from_u8(x).is_some() {
+            None
+        } else {
+            Some(Self(x))
+        }
+    }
+
+    #[must_use]
+    pub const fn get(self) -> u8 {
+        self.0
+    }
+}
+
+impl From<UnknownAddress> for u8 {
+    fn from(from: UnknownAddress) -> Self {
+        from.0
+    }
+}
+
+impl From<u8> for CommandAddress {
+    fn from(from: u8) -> Self {
+        Address::from_u8(from).map_or(Self::Unknown(UnknownAddress(from)), Self::Known)
+    }
+}
+
+impl From<Address> for CommandAddress {
+    fn from(from: Address) -> Self {
+        Self::Known(from)
+    }
+}
+
+impl From<CommandAddress> for u8 {
+    fn from(from: CommandAddress) -> Self {
+        match from {
+            CommandAddress::Known(x) => x.into(),
+            CommandAddress::Unknown(x) => x.into(),
+        }
+    }
+}
+
+/// [`Command`] with a typed address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TypedCommand {
+    pub sender: Sender,
+    pub address: CommandAddress,
+    pub data: Option<u16>,
+}
+
+impl TypedCommand {
+    #[must_use]
+    pub fn into_bytes(self) -> [u8; 10] {
+        Command::from(self).into_bytes()
+    }
+}
+
+impl From<Command> for TypedCommand {
+    fn from(from: Command) -> Self {
+        let Command {
+            sender,
+            address,
+            data,
+        } = from;
+        Self {
+            sender,
+            address: address.into(),
+            data,
+        }
+    }
+}
+
+impl From<TypedCommand> for Command {
+    fn from(from: TypedCommand) -> Self {
+        let TypedCommand {
+            sender,
+            address,
+            data,
+        } = from;
+        Self {
+            sender,
+            address: address.into(),
+            data,
+        }
+    }
+}
+
+impl FromStr for TypedCommand {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        Command::from_str(s).map(Into::into)
+    }
+}
@@ -0,0 +1,162 @@
+use core::{fmt, ops::RangeInclusive};
+#[cfg(feature = "std")]
+use std::io::{Error, ErrorKind};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Address, AnalogSignal, Command, OnOff, OperationLock, Sender, TempControlMode, Temperature,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Range of temperatures the device accepts as setpoint.
+///
+/// The lowest encodable temperature (`0x8000`) is reserved
+/// by the device to mark missing values.
+pub const SETPOINT_RANGE: RangeInclusive<Temperature> =
+    RangeInclusive::new(Temperature::from_centi_celsius(-15_100), Temperature::MAX);
+
+/// Range of the deviation limits from the setpoint.
+///
+/// The deviation is given as a positive temperature difference.
+pub const DELTA_LIMIT_RANGE: RangeInclusive<Temperature> =
+    RangeInclusive::new(Temperature::from_centi_celsius(0), Temperature::MAX);
+
+/// A command that the device would reject.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ValidationError {
+    /// Unknown command address
+    Address(u8),
+    /// The address can only be read
+    ReadOnly(Address),
+    /// The data is out of the legal range of the address
+    OutOfRange { address: Address, data: u16 },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::Address(x) => write!(f, "Unknown command address: {x:#04X}"),
+            Self::ReadOnly(address) => write!(f, "{address:?} is read-only"),
+            Self::OutOfRange { address, data } => {
+                write!(f, "Value {data:#06X} is out of range for {address:?}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ValidationError {}
+
+#[cfg(feature = "std")]
+impl From<ValidationError> for Error {
+    fn from(e: ValidationError) -> Error {
+        Error::new(ErrorKind::InvalidInput, e)
+    }
+}
+
+impl Address {
+    /// Returns `false` if the address can only be read.
+    #[must_use]
+    pub const fn is_writable(self) -> bool {
+        !matches!(
+            self,
+            Self::InternalTemp
+                | Self::PumpPressure
+                | Self::CurrentPower
+                | Self::ErrorReport
+                | Self::WarningMessage
+                | Self::ProcessTemp
+                | Self::Status
+                | Self::PumpSpeed
+                | Self::ComGateDigitalInputs
+                | Self::ComGateAnalogInput
+                | Self::EcsStandby
+                | Self::DeviceType
+                | Self::FirmwareVersion
+                | Self::SerialNumberHigh
+                | Self::SerialNumberLow
+        )
+    }
+
+    /// Check if `data` is within the legal range of the address.
+    pub fn validate_data(self, data: u16) -> Result<(), ValidationError> {
+        let valid = match self {
+            Self::SetpointTempControl
+            | Self::SetProcessTemp
+            | Self::UpperAlarmLimit
+            | Self::LowerAlarmLimit => SETPOINT_RANGE.contains(&Temperature::from_data(data)),
+            Self::UpperDeltaLimit | Self::LowerDeltaLimit => {
+                DELTA_LIMIT_RANGE.contains(&Temperature::from_data(data))
+            }
+            Self::TempControlMode => TempControlMode::try_from(data).is_ok(),
+            Self::ComGateAnalogInput | Self::ComGateAnalogOutput => {
+                AnalogSignal::from_data(data) <= AnalogSignal::MAX
+            }
+            Self::TempControl | Self::Circulation | Self::Degassing | Self::EcsStandby => {
+                OnOff::try_from(data).is_ok()
+            }
+            Self::OperationLock => OperationLock::try_from(data).is_ok(),
+            // These registers have no range of valid values,
+            // every data word decodes to a response.
+            Self::InternalTemp
+            | Self::ErrorReport
+            | Self::WarningMessage
+            | Self::ProcessTemp
+            | Self::Status
+            | Self::ProcessTempActualSettingMode
+            | Self::PumpPressure
+            | Self::CurrentPower
+            | Self::PumpSpeed
+            | Self::PumpSpeedSetpoint
+            | Self::ComGateDigitalInputs
+            | Self::ComGateDigitalOutputs
+            | Self::DeviceType
+            | Self::FirmwareVersion
+            | Self::SerialNumberHigh
+            | Self::SerialNumberLow => true,
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(ValidationError::OutOfRange {
+                address: self,
+                data,
+            })
+        }
+    }
+}
+
+impl Command {
+    /// Check the command before it is sent to the device.
+    ///
+    /// Master commands must not write read-only addresses.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let address =
+            Address::from_u8(self.address).ok_or(ValidationError::Address(self.address))?;
+        let Some(data) = self.data else {
+            return Ok(());
+        };
+        if self.sender == Sender::Master && !address.is_writable() {
+            return Err(ValidationError::ReadOnly(address));
+        }
+        address.validate_data(data)
+    }
+
+    /// Like [`Command::set`] but fails if the device would reject the command.
+    pub fn try_set(address: Address, data: u16) -> Result<Self, ValidationError> {
+        let cmd = Self::set(address, data);
+        cmd.validate()?;
+        Ok(cmd)
+    }
+
+    /// Set the setpoint of the temperature controller.
+    pub fn try_set_setpoint(temp: Temperature) -> Result<Self, ValidationError> {
+        Self::try_set(Address::SetpointTempControl, temp.to_data())
+    }
+}
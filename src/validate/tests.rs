@@ -0,0 +1,88 @@
+use super::*;
+use crate::AlarmLimit;
+
+#[test]
+fn validate_setpoint() {
+    let temp = Temperature::from_centi_celsius(2500);
+    assert!(Command::try_set_setpoint(temp).is_ok());
+    assert!(Command::try_set_setpoint(*SETPOINT_RANGE.start()).is_ok());
+    assert_eq!(
+        Command::try_set_setpoint(Temperature::MIN),
+        Err(ValidationError::OutOfRange {
+            address: Address::SetpointTempControl,
+            data: 0x8000
+        })
+    );
+    assert!(Address::SetProcessTemp.validate_data(0xC4E3).is_err());
+}
+
+#[test]
+fn validate_enumerations() {
+    assert!(Command::try_set(Address::TempControlMode, 1).is_ok());
+    assert!(Command::try_set(Address::TempControlMode, 2).is_err());
+    for address in [
+        Address::TempControl,
+        Address::Circulation,
+        Address::OperationLock,
+        Address::Degassing,
+    ] {
+        assert!(Command::try_set(address, 0).is_ok());
+        assert!(Command::try_set(address, 1).is_ok());
+        assert_eq!(
+            Command::try_set(address, 2),
+            Err(ValidationError::OutOfRange { address, data: 2 })
+        );
+    }
+}
+
+#[test]
+fn validate_read_only() {
+    assert!(Command::query(Address::InternalTemp).validate().is_ok());
+    assert_eq!(
+        Command::try_set(Address::InternalTemp, 0),
+        Err(ValidationError::ReadOnly(Address::InternalTemp))
+    );
+    let reply = Command {
+        sender: Sender::Slave,
+        address: Address::InternalTemp.into(),
+        data: Some(0x09C4),
+    };
+    assert!(reply.validate().is_ok());
+}
+
+#[test]
+fn validate_unknown_address() {
+    let cmd = Command {
+        sender: Sender::Master,
+        address: 0x7F,
+        data: None,
+    };
+    assert_eq!(cmd.validate(), Err(ValidationError::Address(0x7F)));
+}
+
+#[test]
+fn validate_alarm_limits() {
+    for limit in [AlarmLimit::Upper, AlarmLimit::Lower] {
+        let address = limit.address();
+        assert!(Command::try_set(address, Temperature::MAX.to_data()).is_ok());
+        assert!(Command::try_set(address, Temperature::from_centi_celsius(-15_100).into()).is_ok());
+        assert!(Command::try_set(address, Temperature::MIN.to_data()).is_err());
+    }
+    for limit in [AlarmLimit::UpperDelta, AlarmLimit::LowerDelta] {
+        let address = limit.address();
+        assert!(Command::try_set(address, 0).is_ok());
+        assert!(Command::try_set(address, Temperature::MAX.to_data()).is_ok());
+        assert_eq!(
+            Command::try_set(address, 0xFFFF),
+            Err(ValidationError::OutOfRange {
+                address,
+                data: 0xFFFF
+            })
+        );
+    }
+    assert_eq!(
+        AlarmLimit::try_from(Address::LowerDeltaLimit),
+        Ok(AlarmLimit::LowerDelta)
+    );
+    assert!(AlarmLimit::try_from(Address::SetpointTempControl).is_err());
+}
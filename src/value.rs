@@ -0,0 +1,133 @@
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io::{Error, ErrorKind};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Data word that is not valid for the address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct InvalidValue(pub u16);
+
+impl fmt::Display for InvalidValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Invalid value: {:#06X}", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidValue {}
+
+#[cfg(feature = "std")]
+impl From<InvalidValue> for Error {
+    fn from(e: InvalidValue) -> Error {
+        Error::new(ErrorKind::InvalidData, e)
+    }
+}
+
+/// Value of [`Address::TempControlMode`](crate::Address::TempControlMode).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TempControlMode {
+    /// Control the internal temperature
+    Internal = 0,
+    /// Control the process temperature
+    Process = 1,
+}
+
+impl TryFrom<u16> for TempControlMode {
+    type Error = InvalidValue;
+    fn try_from(from: u16) -> Result<Self, InvalidValue> {
+        match from {
+            0 => Ok(Self::Internal),
+            1 => Ok(Self::Process),
+            x => Err(InvalidValue(x)),
+        }
+    }
+}
+
+impl From<TempControlMode> for u16 {
+    fn from(from: TempControlMode) -> Self {
+        from as u16
+    }
+}
+
+/// Value of boolean addresses like [`Address::TempControl`](crate::Address::TempControl).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum OnOff {
+    Off = 0,
+    On = 1,
+}
+
+impl OnOff {
+    #[must_use]
+    pub const fn is_on(self) -> bool {
+        matches!(self, Self::On)
+    }
+}
+
+impl From<bool> for OnOff {
+    fn from(from: bool) -> Self {
+        if from {
+            Self::On
+        } else {
+            Self::Off
+        }
+    }
+}
+
+impl From<OnOff> for bool {
+    fn from(from: OnOff) -> Self {
+        from.is_on()
+    }
+}
+
+impl TryFrom<u16> for OnOff {
+    type Error = InvalidValue;
+    fn try_from(from: u16) -> Result<Self, InvalidValue> {
+        match from {
+            0 => Ok(Self::Off),
+            1 => Ok(Self::On),
+            x => Err(InvalidValue(x)),
+        }
+    }
+}
+
+impl From<OnOff> for u16 {
+    fn from(from: OnOff) -> Self {
+        from as u16
+    }
+}
+
+/// Value of [`Address::OperationLock`](crate::Address::OperationLock).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum OperationLock {
+    /// The device can be operated from the front panel
+    Unlocked = 0,
+    /// Operation from the front panel is locked
+    Locked = 1,
+}
+
+impl TryFrom<u16> for OperationLock {
+    type Error = InvalidValue;
+    fn try_from(from: u16) -> Result<Self, InvalidValue> {
+        match from {
+            0 => Ok(Self::Unlocked),
+            1 => Ok(Self::Locked),
+            x => Err(InvalidValue(x)),
+        }
+    }
+}
+
+impl From<OperationLock> for u16 {
+    fn from(from: OperationLock) -> Self {
+        from as u16
+    }
+}
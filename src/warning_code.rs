@@ -0,0 +1,132 @@
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Warning code reported by [`Address::WarningMessage`](crate::Address::WarningMessage).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum WarningCode {
+    /// No warning
+    NoWarning,
+    /// Internal temperature above the upper warning limit
+    HighTemperature,
+    /// Internal temperature below the lower warning limit
+    LowTemperature,
+    /// Level of thermal fluid getting low
+    LowLevel,
+    /// Maintenance interval expired
+    MaintenanceDue,
+    /// Process temperature sensor not connected
+    ProcessSensorMissing,
+    /// Condenser filter clogged
+    FilterClogged,
+    /// Device restarted after a power failure
+    PowerFailure,
+    /// Temperature control stopped by the interface watchdog
+    WatchdogTimeout,
+    /// Undocumented warning code
+    Unknown(u16),
+}
+
+/// How serious a [`WarningCode`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Severity {
+    /// Informational, no action required
+    Info,
+    /// Recoverable, the device keeps operating
+    Warning,
+    /// The device stopped operating
+    Fault,
+}
+
+impl WarningCode {
+    #[must_use]
+    pub const fn from_u16(x: u16) -> Self {
+        match x {
+            0 => Self::NoWarning,
+            1 => Self::HighTemperature,
+            2 => Self::LowTemperature,
+            3 => Self::LowLevel,
+            4 => Self::MaintenanceDue,
+            5 => Self::ProcessSensorMissing,
+            6 => Self::FilterClogged,
+            7 => Self::PowerFailure,
+            8 => Self::WatchdogTimeout,
+            x => Self::Unknown(x),
+        }
+    }
+
+    #[must_use]
+    pub const fn code(self) -> u16 {
+        match self {
+            Self::NoWarning => 0,
+            Self::HighTemperature => 1,
+            Self::LowTemperature => 2,
+            Self::LowLevel => 3,
+            Self::MaintenanceDue => 4,
+            Self::ProcessSensorMissing => 5,
+            Self::FilterClogged => 6,
+            Self::PowerFailure => 7,
+            Self::WatchdogTimeout => 8,
+            Self::Unknown(x) => x,
+        }
+    }
+
+    #[must_use]
+    pub const fn is_warning(self) -> bool {
+        !matches!(self, Self::NoWarning)
+    }
+
+    /// Unknown codes are classified as [`Severity::Fault`] to be on the safe side.
+    #[must_use]
+    pub const fn severity(self) -> Severity {
+        match self {
+            Self::NoWarning | Self::MaintenanceDue | Self::PowerFailure => Severity::Info,
+            Self::HighTemperature
+            | Self::LowTemperature
+            | Self::LowLevel
+            | Self::ProcessSensorMissing
+            | Self::FilterClogged => Severity::Warning,
+            Self::WatchdogTimeout | Self::Unknown(_) => Severity::Fault,
+        }
+    }
+
+    /// Human-readable description.
+    #[must_use]
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::NoWarning => "No warning",
+            Self::HighTemperature => "Internal temperature above the upper warning limit",
+            Self::LowTemperature => "Internal temperature below the lower warning limit",
+            Self::LowLevel => "Level of thermal fluid getting low",
+            Self::MaintenanceDue => "Maintenance interval expired",
+            Self::ProcessSensorMissing => "Process temperature sensor not connected",
+            Self::FilterClogged => "Condenser filter clogged",
+            Self::PowerFailure => "Device restarted after a power failure",
+            Self::WatchdogTimeout => "Temperature control stopped by the interface watchdog",
+            Self::Unknown(_) => "Unknown warning",
+        }
+    }
+}
+
+impl From<u16> for WarningCode {
+    fn from(from: u16) -> Self {
+        Self::from_u16(from)
+    }
+}
+
+impl From<WarningCode> for u16 {
+    fn from(from: WarningCode) -> Self {
+        from.code()
+    }
+}
+
+impl fmt::Display for WarningCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (warning {})", self.description(), self.code())
+    }
+}